@@ -0,0 +1,283 @@
+use crate::Text;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// A terminal style applied by [`Text::highlighted`], using ANSI SGR escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnsiStyle {
+    Bold,
+    Underline,
+    Reverse,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl AnsiStyle {
+    /// Gets the escape sequence that switches this style on.
+    pub fn escape(&self) -> &'static str {
+        match self {
+            AnsiStyle::Bold => "\x1b[1m",
+            AnsiStyle::Underline => "\x1b[4m",
+            AnsiStyle::Reverse => "\x1b[7m",
+            AnsiStyle::Red => "\x1b[31m",
+            AnsiStyle::Green => "\x1b[32m",
+            AnsiStyle::Yellow => "\x1b[33m",
+            AnsiStyle::Blue => "\x1b[34m",
+            AnsiStyle::Magenta => "\x1b[35m",
+            AnsiStyle::Cyan => "\x1b[36m",
+        }
+    }
+}
+
+/// The escape sequence resetting all styles.
+const RESET: &str = "\x1b[0m";
+
+/// A [`Text`] with one span highlighted, created by [`Text::highlighted`].
+/// Nothing is allocated; the escape sequences are only written when displayed.
+#[derive(Debug, Clone)]
+pub struct Highlighted<'a> {
+    text: &'a Text,
+    span: Range<usize>,
+    style: AnsiStyle,
+}
+
+impl Display for Highlighted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = self.text.as_str();
+        let Range { start, end } = self.span;
+        f.write_str(&s[..start])?;
+        if start < end {
+            f.write_str(self.style.escape())?;
+            f.write_str(&s[start..end])?;
+            f.write_str(RESET)?;
+        }
+        f.write_str(&s[end..])
+    }
+}
+
+/// A piece of a [`Text`] containing ANSI escape sequences, yielded by [`Text::ansi_segments`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnsiSegment {
+    /// A run of text without escape sequences.
+    Text(Text),
+    /// A single escape sequence, including the leading `ESC`.
+    Escape(Text),
+}
+
+/// Gets the length of the escape sequence at the start of `bytes`, which starts with `ESC`.
+/// Handles CSI (`ESC [ ... final`) and OSC (`ESC ] ... BEL` or `ESC ] ... ESC \`) sequences as well as other escapes
+/// made of intermediate bytes and a final byte.
+/// Unterminated sequences extend to the end of `bytes`.
+fn escape_len(bytes: &[u8]) -> usize {
+    match bytes.get(1) {
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map_or(bytes.len(), |i| i + 3),
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return i + 1,
+                    0x1b if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    _ => i += 1,
+                }
+            }
+            bytes.len()
+        }
+        Some(b) if b.is_ascii() => {
+            // Intermediate bytes, like `(` in `ESC ( B`, are followed by a final byte.
+            // Without a valid final byte, the escape ends before whatever follows.
+            let intermediates = bytes[1..]
+                .iter()
+                .take_while(|b| (0x20..=0x2f).contains(*b))
+                .count();
+            match bytes.get(intermediates + 1) {
+                Some(b) if (0x30..=0x7e).contains(b) => intermediates + 2,
+                _ => intermediates + 1,
+            }
+        }
+        _ => 1,
+    }
+}
+
+impl Text {
+    /// Splits the [`Text`] into runs of plain text and ANSI escape sequences, both as slices of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{AnsiSegment, Text};
+    ///
+    /// let line = Text::new("\x1b[31merror\x1b[0m: oops");
+    /// let segments: Vec<AnsiSegment> = line.ansi_segments().collect();
+    /// assert_eq!(4, segments.len());
+    /// assert_eq!(AnsiSegment::Text(Text::new("error")), segments[1]);
+    /// assert_eq!(AnsiSegment::Escape(Text::new("\x1b[0m")), segments[2]);
+    /// ```
+    pub fn ansi_segments(&self) -> impl Iterator<Item = AnsiSegment> + '_ {
+        let mut pos = 0;
+        std::iter::from_fn(move || {
+            let rest = &self.as_str().as_bytes()[pos..];
+            let start = pos;
+            match memchr::memchr(0x1b, rest) {
+                None if rest.is_empty() => None,
+                Some(0) => {
+                    pos += escape_len(rest);
+                    Some(AnsiSegment::Escape(self.slice(start, pos)))
+                }
+                next => {
+                    pos += next.unwrap_or(rest.len());
+                    Some(AnsiSegment::Text(self.slice(start, pos)))
+                }
+            }
+        })
+    }
+
+    /// Removes all ANSI escape sequences, e.g. from colored log output.
+    /// If there are none, or all text lies in a single run, the result is a slice of `self` and nothing is copied.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let line = Text::new("\x1b[1mbold\x1b[0m and \x1b]8;;http://x\x07link\x1b]8;;\x07");
+    /// assert_eq!("bold and link", line.strip_ansi().as_str());
+    /// ```
+    pub fn strip_ansi(&self) -> Text {
+        let mut runs = self.ansi_segments().filter_map(|segment| match segment {
+            AnsiSegment::Text(text) => Some(text),
+            AnsiSegment::Escape(_) => None,
+        });
+        let first = match runs.next() {
+            Some(first) => first,
+            None => return self.slice(0, 0),
+        };
+        match runs.next() {
+            None => first,
+            Some(second) => {
+                let mut stripped = String::with_capacity(self.len());
+                stripped.push_str(first.as_str());
+                stripped.push_str(second.as_str());
+                runs.for_each(|run| stripped.push_str(run.as_str()));
+                Text::new(stripped.as_str())
+            }
+        }
+    }
+
+    /// Counts the `char`s outside of ANSI escape sequences, i.e. the width of the [`Text`] in a terminal
+    /// if every `char` takes up one column.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!(4, Text::new("\x1b[32mgrün\x1b[0m").ansi_width());
+    /// ```
+    pub fn ansi_width(&self) -> usize {
+        self.ansi_segments()
+            .map(|segment| match segment {
+                AnsiSegment::Text(text) => text.char_len(),
+                AnsiSegment::Escape(_) => 0,
+            })
+            .sum()
+    }
+
+    /// Displays the [`Text`] with the byte range `span` highlighted in `style`, e.g. to show a search match in a terminal.
+    /// Empty spans are displayed without escape sequences.
+    /// Will panic if the span exceeds the [`Text`]'s bounds or does not start and end on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{AnsiStyle, Text};
+    ///
+    /// let line = Text::new("let x = 1;");
+    /// let shown = line.highlighted(4..5, AnsiStyle::Underline).to_string();
+    /// assert_eq!("let \x1b[4mx\x1b[0m = 1;", shown);
+    /// ```
+    pub fn highlighted(&self, span: Range<usize>, style: AnsiStyle) -> Highlighted<'_> {
+        // Validates the span, panicking with a descriptive message
+        self.slice(span.start, span.end);
+        Highlighted {
+            text: self,
+            span,
+            style,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AnsiSegment, AnsiStyle, Text};
+
+    #[test]
+    pub fn test_highlighted() {
+        let t = Text::new("xañbx").slice(1, 5);
+        assert_eq!(
+            "a\x1b[31mñ\x1b[0mb",
+            t.highlighted(1..3, AnsiStyle::Red).to_string()
+        );
+        assert_eq!(
+            "\x1b[7mañb\x1b[0m",
+            t.highlighted(0..4, AnsiStyle::Reverse).to_string()
+        );
+        assert_eq!("añb", t.highlighted(3..3, AnsiStyle::Bold).to_string());
+    }
+
+    #[test]
+    pub fn test_strip_ansi() {
+        let t = Text::new("\x1b[1;31mred\x1b[0m \x1b]0;title\x1b\\ok\x1b(Bx\x1b[");
+        assert_eq!("red okx", t.strip_ansi().as_str());
+        assert_eq!(7, t.ansi_width());
+        let escapes: Vec<Text> = t
+            .ansi_segments()
+            .filter_map(|s| match s {
+                AnsiSegment::Escape(e) => Some(e),
+                AnsiSegment::Text(_) => None,
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                "\x1b[1;31m",
+                "\x1b[0m",
+                "\x1b]0;title\x1b\\",
+                "\x1b(B",
+                "\x1b["
+            ],
+            escapes.iter().map(Text::as_str).collect::<Vec<_>>()
+        );
+        let single = Text::new("\x1b[4mplain\x1b[0m");
+        let stripped = single.strip_ansi();
+        assert_eq!("plain", stripped.as_str());
+        assert_eq!(single.as_str()[4..].as_ptr(), stripped.as_str().as_ptr());
+        assert_eq!("", Text::new("\x1b[0m").strip_ansi().as_str());
+    }
+
+    #[test]
+    pub fn test_unterminated_escape_before_multibyte() {
+        let cases = [
+            ("\x1b(ä", "\x1b(", "ä"),
+            ("\x1b ä", "\x1b ", "ä"),
+            ("a\x1b(é b", "\x1b(", "aé b"),
+        ];
+        for (input, escape, stripped) in cases {
+            let t = Text::new(input);
+            assert_eq!(stripped, t.strip_ansi().as_str());
+            assert_eq!(stripped.chars().count(), t.ansi_width());
+            let escapes: Vec<AnsiSegment> = t
+                .ansi_segments()
+                .filter(|s| matches!(s, AnsiSegment::Escape(_)))
+                .collect();
+            assert_eq!(vec![AnsiSegment::Escape(Text::new(escape))], escapes);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_highlighted_invalid() {
+        Text::new("añb").highlighted(0..2, AnsiStyle::Bold);
+    }
+}
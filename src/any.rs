@@ -0,0 +1,96 @@
+use crate::{Text, TextRef};
+
+/// The operations shared by all of **quetta**'s string types, so that functions can be written once for any of them.
+///
+/// # Example
+/// ```
+/// use quetta::{AnyText, Text, TextRef};
+///
+/// fn first_word<T: AnyText>(text: &T) -> T {
+///     let end = text.as_str().find(' ').unwrap_or(text.len());
+///     text.slice(0, end)
+/// }
+///
+/// assert_eq!("hello", first_word(&Text::new("hello world")).as_str());
+/// assert_eq!("hello", first_word(&TextRef::new("hello world")).as_str());
+/// ```
+pub trait AnyText: Sized {
+    /// Gets the text as a slice.
+    fn as_str(&self) -> &str;
+
+    /// Creates another text of the same type with a provided start code point and end code point.
+    /// Will panic if the slice exceeds the text's bounds or does not start and end on a `char` boundary.
+    fn slice(&self, start: usize, end: usize) -> Self;
+
+    /// Attempt to create a text of the same type from a slice sliced from this text.
+    /// Will return `None` if the `slice` is not contained in `self`.
+    fn try_lift_slice(&self, slice: &str) -> Option<Self>;
+
+    /// Converts the text into a [`Text`], without copying where possible.
+    fn to_text(&self) -> Text;
+
+    /// Gets the length of the text in bytes.
+    fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Is the text empty?
+    fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+}
+
+impl AnyText for Text {
+    fn as_str(&self) -> &str {
+        Text::as_str(self)
+    }
+
+    fn slice(&self, start: usize, end: usize) -> Self {
+        Text::slice(self, start, end)
+    }
+
+    fn try_lift_slice(&self, slice: &str) -> Option<Self> {
+        Text::try_lift_slice(self, slice)
+    }
+
+    fn to_text(&self) -> Text {
+        self.clone()
+    }
+}
+
+impl AnyText for TextRef<'_> {
+    fn as_str(&self) -> &str {
+        TextRef::as_str(self)
+    }
+
+    fn slice(&self, start: usize, end: usize) -> Self {
+        TextRef::slice(self, start, end)
+    }
+
+    fn try_lift_slice(&self, slice: &str) -> Option<Self> {
+        TextRef::try_lift_slice(self, slice)
+    }
+
+    fn to_text(&self) -> Text {
+        self.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AnyText, Text, TextRef};
+
+    fn trimmed<T: AnyText>(text: &T) -> Option<T> {
+        text.try_lift_slice(text.as_str().trim())
+    }
+
+    #[test]
+    pub fn test_any_text() {
+        let t = Text::new(" a ");
+        assert_eq!("a", trimmed(&t).unwrap().as_str());
+        assert_eq!("a", trimmed(&t.as_text_ref()).unwrap().as_str());
+        let r = TextRef::new("xy");
+        assert_eq!(Text::new("y"), AnyText::slice(&r, 1, 2).to_text());
+        assert!(!AnyText::is_empty(&r));
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{Text, TextSeq};
+use std::ops::Range;
+
+/// A growable text that is only ever appended to, e.g. a log being assembled.
+/// Appended data is first kept in a pending buffer; [`AppendOnlyText::commit`] freezes it into an immutable chunk,
+/// after which every committed entry can be retrieved as a zero-copy [`Text`] slice.
+///
+/// # Example
+/// ```
+/// use quetta::AppendOnlyText;
+///
+/// let mut log = AppendOnlyText::new();
+/// let first = log.append("started\n");
+/// let second = log.append("listening on :80\n");
+/// assert_eq!(None, log.entry(first));
+/// log.commit();
+/// assert_eq!("listening on :80\n", log.entry(second).unwrap().as_str());
+/// assert_eq!("started\nlistening on :80\n", log.snapshot().to_string());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AppendOnlyText {
+    chunks: Vec<Text>,
+    /// Global offset of the start of every chunk.
+    chunk_starts: Vec<usize>,
+    committed_len: usize,
+    pending: String,
+    /// Global byte range of every appended entry.
+    entries: Vec<Range<usize>>,
+}
+
+impl AppendOnlyText {
+    /// Creates an empty [`AppendOnlyText`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `s` as a new entry, returning the index of the entry.
+    pub fn append(&mut self, s: &str) -> usize {
+        let start = self.len();
+        self.pending.push_str(s);
+        self.entries.push(start..start + s.len());
+        self.entries.len() - 1
+    }
+
+    /// Gets the total length of all appended data, committed or not.
+    pub fn len(&self) -> usize {
+        self.committed_len + self.pending.len()
+    }
+
+    /// Has nothing been appended yet?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the length of the committed data.
+    pub fn committed_len(&self) -> usize {
+        self.committed_len
+    }
+
+    /// Gets the data appended since the last commit.
+    pub fn pending(&self) -> &str {
+        &self.pending
+    }
+
+    /// Gets the number of appended entries.
+    pub fn entries_len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Freezes all pending data into a new immutable chunk, which is returned.
+    /// Returns an empty [`Text`] if nothing is pending.
+    pub fn commit(&mut self) -> Text {
+        if self.pending.is_empty() {
+            return Text::default();
+        }
+        let chunk = Text::from_owner(std::mem::take(&mut self.pending));
+        self.chunk_starts.push(self.committed_len);
+        self.committed_len += chunk.len();
+        self.chunks.push(chunk.clone());
+        chunk
+    }
+
+    /// Gets the committed chunks.
+    pub fn chunks(&self) -> &[Text] {
+        &self.chunks
+    }
+
+    /// Gets a committed range of the data. This is zero-copy if the range lies within a single chunk;
+    /// otherwise, the range is copied once.
+    /// Returns `None` if the range is not committed yet or not on `char` boundaries.
+    pub fn slice(&self, start: usize, end: usize) -> Option<Text> {
+        if start > end || end > self.committed_len {
+            return None;
+        }
+        let first = self
+            .chunk_starts
+            .partition_point(|s| *s <= start)
+            .checked_sub(1);
+        let first = match first {
+            Some(first) => first,
+            None => return Some(Text::default()),
+        };
+        let chunk_start = self.chunk_starts[first];
+        let chunk = &self.chunks[first];
+        if end - chunk_start <= chunk.len() {
+            return chunk.try_slice(start - chunk_start, end - chunk_start).ok();
+        }
+        let mut pieces = TextSeq::new();
+        for (chunk, chunk_start) in self.chunks[first..].iter().zip(&self.chunk_starts[first..]) {
+            if *chunk_start >= end {
+                break;
+            }
+            let from = start.saturating_sub(*chunk_start);
+            let to = (end - chunk_start).min(chunk.len());
+            pieces.push(chunk.try_slice(from, to).ok()?);
+        }
+        Some(pieces.to_text())
+    }
+
+    /// Gets a committed entry as a zero-copy slice of its chunk.
+    /// Returns `None` if there is no such entry or it has not been committed yet.
+    pub fn entry(&self, index: usize) -> Option<Text> {
+        let range = self.entries.get(index)?;
+        self.slice(range.start, range.end)
+    }
+
+    /// Gets all committed data as a [`TextSeq`] of the chunks, without copying.
+    /// Call [`AppendOnlyText::compact`] first if a single contiguous [`Text`] is needed.
+    pub fn snapshot(&self) -> TextSeq {
+        self.chunks.iter().cloned().collect()
+    }
+
+    /// Merges all committed chunks into a single one, so that every committed range can be sliced without copying.
+    /// [`Text`]s handed out before remain valid, but keep their old chunks alive.
+    pub fn compact(&mut self) {
+        if self.chunks.len() > 1 {
+            let merged = self.snapshot().to_text();
+            self.chunks = vec![merged];
+            self.chunk_starts = vec![0];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AppendOnlyText;
+
+    #[test]
+    pub fn test_append_and_commit() {
+        let mut log = AppendOnlyText::new();
+        assert!(log.is_empty());
+        assert_eq!("", log.commit().as_str());
+        let a = log.append("aä");
+        let b = log.append("b");
+        assert_eq!("aäb", log.commit().as_str());
+        assert_eq!(2, log.entries_len());
+        let c = log.append("cc");
+        assert_eq!("cc", log.pending());
+        assert_eq!(4, log.committed_len());
+        assert_eq!(6, log.len());
+        assert_eq!(None, log.entry(c));
+        let pending = log.pending().as_ptr();
+        assert_eq!(pending, log.commit().as_str().as_ptr());
+        assert_eq!(2, log.chunks().len());
+        assert_eq!("aä", log.entry(a).unwrap().as_str());
+        assert_eq!("b", log.entry(b).unwrap().as_str());
+        assert_eq!("cc", log.entry(c).unwrap().as_str());
+        assert_eq!(None, log.entry(3));
+        assert_eq!("bc", log.slice(3, 5).unwrap().as_str());
+        assert_eq!(None, log.slice(2, 5));
+        assert_eq!("", log.slice(6, 6).unwrap().as_str());
+        assert_eq!(None, log.slice(5, 7));
+        let snapshot = log.snapshot();
+        assert_eq!("aäbcc", snapshot.to_string());
+        assert_eq!(log.chunks(), snapshot.pieces());
+        log.compact();
+        assert_eq!(1, log.chunks().len());
+        let snapshot = log.snapshot().to_text();
+        assert_eq!(
+            snapshot.as_str().as_ptr(),
+            log.entry(b).unwrap().as_str().as_ptr().wrapping_sub(3)
+        );
+    }
+}
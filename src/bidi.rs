@@ -0,0 +1,82 @@
+use crate::Text;
+use unicode_bidi::BidiInfo;
+
+/// A maximal run of text with a single embedding level, as determined by the Unicode Bidirectional Algorithm.
+/// See [`Text::bidi_runs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidiRun {
+    text: Text,
+    level: u8,
+}
+
+impl BidiRun {
+    /// Gets the text of the run.
+    pub fn text(&self) -> &Text {
+        &self.text
+    }
+
+    /// Gets the embedding level of the run. Even levels are left-to-right, odd levels right-to-left.
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Is the run displayed right-to-left?
+    pub fn is_rtl(&self) -> bool {
+        self.level % 2 == 1
+    }
+}
+
+impl Text {
+    /// Splits the [`Text`] into runs of equal embedding level, in logical order, as slices of the [`Text`].
+    /// The base direction of every paragraph is determined from its first strong character.
+    /// Renderers reorder the runs of each line by level for display.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("abc שלום def");
+    /// let runs = text.bidi_runs();
+    /// assert_eq!(3, runs.len());
+    /// assert_eq!("שלום", runs[1].text().as_str());
+    /// assert!(runs[1].is_rtl());
+    /// assert!(!runs[2].is_rtl());
+    /// ```
+    pub fn bidi_runs(&self) -> Vec<BidiRun> {
+        let s = self.as_str();
+        let info = BidiInfo::new(s, None);
+        let mut runs = Vec::new();
+        let mut start = 0;
+        let ends = s.char_indices().skip(1).map(|(i, _)| i);
+        for end in ends.chain(std::iter::once(s.len())) {
+            if end > start && (end == s.len() || info.levels[end] != info.levels[start]) {
+                runs.push(BidiRun {
+                    text: self.slice(start, end),
+                    level: info.levels[start].number(),
+                });
+                start = end;
+            }
+        }
+        runs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_bidi_runs() {
+        let t = Text::new("שלום abc 123 עולם\nplain");
+        let runs = t.bidi_runs();
+        let joined: String = runs.iter().map(|r| r.text().as_str()).collect();
+        assert_eq!(t.as_str(), joined);
+        assert_eq!(1, runs[0].level());
+        assert_eq!("שלום ", runs[0].text().as_str());
+        assert_eq!(2, runs[1].level());
+        assert_eq!("abc 123", runs[1].text().as_str());
+        assert_eq!(0, runs.last().unwrap().level());
+        assert_eq!("plain", runs.last().unwrap().text().as_str());
+        assert!(Text::default().bidi_runs().is_empty());
+    }
+}
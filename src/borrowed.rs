@@ -0,0 +1,243 @@
+use crate::{SliceError, Text};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// A borrowed sibling of [`Text`] with the same slicing API, but without reference counting.
+/// Slicing a [`TextRef`] never allocates or touches a reference count, which makes it suitable for hot parsing stages.
+/// Only the pieces that are kept need to be promoted with [`TextRef::to_owned`], which is zero-copy
+/// if the [`TextRef`] was borrowed from a [`Text`].
+///
+/// # Example
+/// ```
+/// use quetta::{Text, TextRef};
+///
+/// let source = Text::new("key = value");
+/// let view = source.as_text_ref();
+/// let key = view.slice(0, 3);
+/// let owned: Text = key.to_owned();
+/// assert_eq!("key", owned.as_str());
+/// assert_eq!(source.as_str().as_ptr(), owned.as_str().as_ptr());
+///
+/// let borrowed = TextRef::new("no refcount");
+/// assert_eq!("refcount", borrowed.slice(3, 11).as_str());
+/// ```
+#[derive(Clone, Copy)]
+pub struct TextRef<'a> {
+    s: &'a str,
+    /// The [`Text`] this was borrowed from, if any, so that promoting does not need to copy.
+    root: Option<&'a Text>,
+}
+
+impl<'a> TextRef<'a> {
+    /// Creates a [`TextRef`] borrowing `s`.
+    pub fn new(s: &'a str) -> Self {
+        Self { s, root: None }
+    }
+
+    /// Gets the [`TextRef`] as a slice, with the lifetime of the borrowed data.
+    pub fn as_str(&self) -> &'a str {
+        self.s
+    }
+
+    /// Gets the length of the [`TextRef`].
+    pub fn len(&self) -> usize {
+        self.s.len()
+    }
+
+    /// Is this [`TextRef`] empty?
+    pub fn is_empty(&self) -> bool {
+        self.s.is_empty()
+    }
+
+    /// Creates another [`TextRef`] with a provided start code point and length, see [`Text::substring`].
+    /// Will panic if the substring exceeds the [`TextRef`]'s bounds or does not start and end on a `char` boundary.
+    pub fn substring(&self, start: usize, len: usize) -> TextRef<'a> {
+        match self.try_substring(start, len) {
+            Ok(text) => text,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Creates another [`TextRef`] with a provided start code point and length, see [`Text::try_substring`].
+    /// Returns an error if the substring exceeds the [`TextRef`]'s bounds or does not start and end on a `char` boundary.
+    pub fn try_substring(&self, start: usize, len: usize) -> Result<TextRef<'a>, SliceError> {
+        let end = match start.checked_add(len) {
+            Some(end) if end <= self.s.len() => end,
+            _ => {
+                return Err(SliceError::OutOfBounds {
+                    text_len: self.s.len(),
+                    start,
+                    len,
+                })
+            }
+        };
+        if !self.s.is_char_boundary(start) {
+            return Err(SliceError::NotCharBoundary { index: start });
+        }
+        if !self.s.is_char_boundary(end) {
+            return Err(SliceError::NotCharBoundary { index: end });
+        }
+        Ok(Self {
+            s: &self.s[start..end],
+            root: self.root,
+        })
+    }
+
+    /// Creates another [`TextRef`] with a provided start code point and end code point, see [`Text::slice`].
+    /// Will panic if the slice exceeds the [`TextRef`]'s bounds or does not start and end on a `char` boundary.
+    pub fn slice(&self, start: usize, end: usize) -> TextRef<'a> {
+        match self.try_slice(start, end) {
+            Ok(text) => text,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Creates another [`TextRef`] with a provided start code point and end code point, see [`Text::try_slice`].
+    /// Returns an error if the slice exceeds the [`TextRef`]'s bounds or does not start and end on a `char` boundary.
+    pub fn try_slice(&self, start: usize, end: usize) -> Result<TextRef<'a>, SliceError> {
+        match end.checked_sub(start) {
+            Some(len) => self.try_substring(start, len),
+            None => Err(SliceError::InvertedRange { start, end }),
+        }
+    }
+
+    /// Attempt to create a [`TextRef`] from a slice sliced from this [`TextRef`].
+    /// Will return `None` if the `slice` is not contained in `self`.
+    pub fn try_lift_slice(&self, slice: &str) -> Option<TextRef<'a>> {
+        crate::get_offset(self.s, slice).map(|offset| self.substring(offset, slice.len()))
+    }
+
+    /// Lifts a function `&str -> &str` so it will be executed on the `&str` self.
+    /// Will return none if the `&str` returned by the function is not contained in `self`.
+    pub fn try_lift<F: Fn(&'a str) -> &'a str>(&self, f: F) -> Option<TextRef<'a>> {
+        self.try_lift_slice(f(self.s))
+    }
+
+    /// Creates a [`Text`] with the same contents. If this was borrowed from a [`Text`] (see [`Text::as_text_ref`]),
+    /// the result is a slice of it; otherwise, the contents are copied.
+    pub fn to_owned(&self) -> Text {
+        self.root
+            .and_then(|root| root.try_lift_slice(self.s))
+            .unwrap_or_else(|| Text::new(self.s))
+    }
+}
+
+impl Text {
+    /// Borrows the [`Text`] as a [`TextRef`], which can be sliced without touching the reference count.
+    pub fn as_text_ref(&self) -> TextRef<'_> {
+        TextRef {
+            s: self.as_str(),
+            root: Some(self),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TextRef<'a> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl<'a> From<&'a Text> for TextRef<'a> {
+    fn from(text: &'a Text) -> Self {
+        text.as_text_ref()
+    }
+}
+
+impl<'a> From<TextRef<'a>> for Text {
+    fn from(text: TextRef<'a>) -> Self {
+        text.to_owned()
+    }
+}
+
+impl Default for TextRef<'_> {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl Debug for TextRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.s)
+    }
+}
+
+impl Display for TextRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.s)
+    }
+}
+
+impl PartialEq for TextRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.s == other.s
+    }
+}
+
+impl Eq for TextRef<'_> {}
+
+impl Hash for TextRef<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.s.hash(state)
+    }
+}
+
+impl PartialOrd for TextRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TextRef<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.s.cmp(other.s)
+    }
+}
+
+impl AsRef<str> for TextRef<'_> {
+    fn as_ref(&self) -> &str {
+        self.s
+    }
+}
+
+impl Borrow<str> for TextRef<'_> {
+    fn borrow(&self) -> &str {
+        self.s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SliceError, Text, TextRef};
+
+    #[test]
+    pub fn test_text_ref() {
+        let t = Text::new("añb");
+        let r = t.as_text_ref();
+        assert_eq!("ñ", r.slice(1, 3).as_str());
+        assert_eq!(
+            Err(SliceError::NotCharBoundary { index: 2 }),
+            r.try_slice(2, 4)
+        );
+        assert_eq!(
+            Err(SliceError::InvertedRange { start: 3, end: 1 }),
+            r.try_slice(3, 1)
+        );
+        let trimmed = TextRef::new("  a ").try_lift(|s| s.trim()).unwrap();
+        assert_eq!(TextRef::new("a"), trimmed);
+        assert!(r.try_lift_slice("b").is_none());
+    }
+
+    #[test]
+    pub fn test_to_owned() {
+        let t = Text::new("xañb").slice(1, 5);
+        let owned = t.as_text_ref().slice(3, 4).to_owned();
+        assert_eq!("b", owned.as_str());
+        assert_eq!(5, owned.backing_len());
+        let copied = TextRef::new(t.as_str()).slice(3, 4).to_owned();
+        assert_eq!(owned, copied);
+        assert_eq!(1, copied.backing_len());
+    }
+}
@@ -0,0 +1,425 @@
+use crate::Text;
+use std::fmt::{Arguments, Display, Formatter, Write};
+use std::ops::Range;
+
+/// Builds a [`Text`] from pieces, remembering where each piece ended up so that
+/// [`TextBuilder::build_shared`] can hand out slices of the final buffer for every piece.
+///
+/// # Example
+/// ```
+/// use quetta::{Text, TextBuilder};
+///
+/// let mut builder = TextBuilder::new();
+/// builder.push_str("fn ");
+/// builder.push_text(&Text::new("main"));
+/// builder.push_str("()");
+/// assert_eq!("fn main()", builder.build().as_str());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TextBuilder {
+    buffer: String,
+    pieces: Vec<Range<usize>>,
+    limit: Option<usize>,
+}
+
+/// The error returned when a push would grow a [`TextBuilder`] beyond its limit, see [`TextBuilder::with_limit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitExceededError {
+    limit: usize,
+    len: usize,
+}
+
+impl LimitExceededError {
+    /// Gets the limit in bytes.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Gets the length in bytes the push would have resulted in.
+    pub fn attempted_len(&self) -> usize {
+        self.len
+    }
+}
+
+impl Display for LimitExceededError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Text of length {} exceeds the limit of {}",
+            self.len, self.limit
+        )
+    }
+}
+
+impl std::error::Error for LimitExceededError {}
+
+impl TextBuilder {
+    /// Creates an empty [`TextBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty [`TextBuilder`] with space for `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: String::with_capacity(capacity),
+            pieces: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Creates an empty [`TextBuilder`] that never grows beyond `max_bytes`, e.g. to cap memory spent on user-controlled text.
+    /// Pushes exceeding the limit are rejected by the `try_push` methods and the [`Write`] implementation,
+    /// while the infallible push methods panic.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::TextBuilder;
+    ///
+    /// let mut builder = TextBuilder::with_limit(8);
+    /// builder.try_push_str("hello").unwrap();
+    /// let err = builder.try_push_str(" world").unwrap_err();
+    /// assert_eq!(11, err.attempted_len());
+    /// assert_eq!("hello", builder.as_str());
+    /// ```
+    pub fn with_limit(max_bytes: usize) -> Self {
+        Self {
+            limit: Some(max_bytes),
+            ..Self::default()
+        }
+    }
+
+    /// Gets the limit in bytes, if there is one.
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Checks that `additional` more bytes fit within the limit.
+    fn check_limit(&self, additional: usize) -> Result<(), LimitExceededError> {
+        match self.limit {
+            Some(limit) if additional > limit.saturating_sub(self.buffer.len()) => {
+                Err(LimitExceededError {
+                    limit,
+                    len: self.buffer.len().saturating_add(additional),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Appends a string as a new piece, or leaves the builder unchanged if that would exceed the limit.
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), LimitExceededError> {
+        self.check_limit(s.len())?;
+        let start = self.buffer.len();
+        self.buffer.push_str(s);
+        self.pieces.push(start..self.buffer.len());
+        Ok(())
+    }
+
+    /// Appends a [`Text`] as a new piece, or leaves the builder unchanged if that would exceed the limit.
+    pub fn try_push_text(&mut self, text: &Text) -> Result<(), LimitExceededError> {
+        self.try_push_str(text.as_str())
+    }
+
+    /// Appends a single `char` as a new piece, or leaves the builder unchanged if that would exceed the limit.
+    pub fn try_push(&mut self, c: char) -> Result<(), LimitExceededError> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Gets the length in bytes of everything pushed so far.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Has nothing been pushed yet?
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Gets everything pushed so far as a slice.
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Reserves space for at least `additional` more bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        self.buffer.reserve(additional)
+    }
+
+    /// Appends a string as a new piece. Will panic if that exceeds the limit.
+    pub fn push_str(&mut self, s: &str) {
+        if let Err(e) = self.try_push_str(s) {
+            panic!("{}", e)
+        }
+    }
+
+    /// Appends a [`Text`] as a new piece. Will panic if that exceeds the limit.
+    pub fn push_text(&mut self, text: &Text) {
+        self.push_str(text.as_str())
+    }
+
+    /// Appends a single `char` as a new piece. Will panic if that exceeds the limit.
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Appends the decimal representation of an integer as a new piece, without going through `fmt`.
+    /// Requires the `itoa` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::TextBuilder;
+    ///
+    /// let mut builder = TextBuilder::new();
+    /// builder.push_int(-42i32);
+    /// builder.push(',');
+    /// builder.push_int(u64::MAX);
+    /// assert_eq!("-42,18446744073709551615", builder.as_str());
+    /// ```
+    #[cfg(feature = "itoa")]
+    pub fn push_int<I: itoa::Integer>(&mut self, value: I) {
+        let mut buffer = itoa::Buffer::new();
+        self.push_str(buffer.format(value))
+    }
+
+    /// Appends the shortest representation of a float that parses back to the same value as a new piece,
+    /// without going through `fmt`. Non-finite values are written as `NaN`, `inf` and `-inf`.
+    /// Requires the `ryu` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::TextBuilder;
+    ///
+    /// let mut builder = TextBuilder::new();
+    /// builder.push_float(0.1f64);
+    /// builder.push(',');
+    /// builder.push_float(2f32);
+    /// assert_eq!("0.1,2.0", builder.as_str());
+    /// ```
+    #[cfg(feature = "ryu")]
+    pub fn push_float<F: ryu::Float>(&mut self, value: F) {
+        let mut buffer = ryu::Buffer::new();
+        self.push_str(buffer.format(value))
+    }
+
+    /// Gets the number of pieces pushed so far.
+    pub fn pieces_len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    /// Creates the final [`Text`], taking over the buffer without copying it again.
+    pub fn build(self) -> Text {
+        Text::from_owner(self.buffer)
+    }
+
+    /// Creates the final [`Text`] without copying, together with slices of it for every pushed piece, in push order.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{Text, TextBuilder};
+    ///
+    /// let mut builder = TextBuilder::with_capacity(16);
+    /// builder.push_text(&Text::new("key"));
+    /// builder.push('=');
+    /// builder.push_text(&Text::new("value"));
+    /// let (text, pieces) = builder.build_shared();
+    /// assert_eq!("key=value", text.as_str());
+    /// assert_eq!("value", pieces[2].as_str());
+    /// assert_eq!(text.try_lift_slice(pieces[2].as_str()), Some(pieces[2].clone()));
+    /// ```
+    pub fn build_shared(self) -> (Text, Vec<Text>) {
+        let text = Text::from_owner(self.buffer);
+        let pieces = self
+            .pieces
+            .into_iter()
+            .map(|r| text.slice(r.start, r.end))
+            .collect();
+        (text, pieces)
+    }
+}
+
+/// Writes into the buffer of a [`TextBuilder`] without recording pieces, see [`TextBuilder::write_fmt`].
+struct UnrecordedWriter<'a>(&'a mut TextBuilder);
+
+impl Write for UnrecordedWriter<'_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0.check_limit(s.len()).map_err(|_| std::fmt::Error)?;
+        self.0.buffer.push_str(s);
+        Ok(())
+    }
+}
+
+/// Each `write!` call becomes a single piece. If it exceeds the limit, the builder is left unchanged.
+impl Write for TextBuilder {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.try_push_str(s).map_err(|_| std::fmt::Error)
+    }
+
+    fn write_fmt(&mut self, args: Arguments<'_>) -> std::fmt::Result {
+        let (start, pieces) = (self.buffer.len(), self.pieces.len());
+        match std::fmt::write(&mut UnrecordedWriter(self), args) {
+            Ok(()) => {
+                self.pieces.push(start..self.buffer.len());
+                Ok(())
+            }
+            Err(e) => {
+                self.buffer.truncate(start);
+                self.pieces.truncate(pieces);
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Text {
+    /// Concatenates `parts` into a single new buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let parts = [Text::new("a"), Text::new("b")];
+    /// assert_eq!("ab", Text::concat(&parts).as_str());
+    /// ```
+    pub fn concat(parts: &[Text]) -> Text {
+        let mut builder = TextBuilder::with_capacity(parts.iter().map(Text::len).sum());
+        for part in parts {
+            builder.push_text(part);
+        }
+        builder.build()
+    }
+
+    /// Concatenates `parts` into a single new buffer, unless the result would be longer than `max_bytes`.
+    /// Nothing is allocated if the limit is exceeded.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let parts = [Text::new("ab"), Text::new("cd")];
+    /// assert_eq!("abcd", Text::try_concat_max(&parts, 4).unwrap().as_str());
+    /// assert_eq!(4, Text::try_concat_max(&parts, 3).unwrap_err().attempted_len());
+    /// ```
+    pub fn try_concat_max(parts: &[Text], max_bytes: usize) -> Result<Text, LimitExceededError> {
+        let len = parts
+            .iter()
+            .fold(0usize, |len, part| len.saturating_add(part.len()));
+        if len > max_bytes {
+            return Err(LimitExceededError {
+                limit: max_bytes,
+                len,
+            });
+        }
+        Ok(Text::concat(parts))
+    }
+
+    /// Concatenates `parts` into a single new buffer and returns it together with slices of it
+    /// corresponding to each of the parts. This moves many small [`Text`]s into one compact allocation
+    /// while keeping a handle for each of them.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let tokens = vec![Text::new("let"), Text::new("x"), Text::new("=")];
+    /// let (arena, lifted) = Text::concat_and_lift(&tokens);
+    /// assert_eq!("letx=", arena.as_str());
+    /// assert_eq!(tokens, lifted);
+    /// ```
+    pub fn concat_and_lift(parts: &[Text]) -> (Text, Vec<Text>) {
+        let mut builder = TextBuilder::with_capacity(parts.iter().map(Text::len).sum());
+        for part in parts {
+            builder.push_text(part);
+        }
+        builder.build_shared()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Text, TextBuilder};
+    use std::fmt::Write;
+
+    #[test]
+    pub fn test_build_shared() {
+        let mut builder = TextBuilder::new();
+        assert!(builder.is_empty());
+        builder.push_text(&Text::new("ä"));
+        builder.push_str("");
+        write!(builder, "{}", 12).unwrap();
+        assert_eq!(3, builder.pieces_len());
+        assert_eq!("ä12", builder.as_str());
+        let buffer = builder.as_str().as_ptr();
+        let (text, pieces) = builder.build_shared();
+        assert_eq!(buffer, text.as_str().as_ptr());
+        let pieces: Vec<&str> = pieces.iter().map(Text::as_str).collect();
+        assert_eq!(vec!["ä", "", "12"], pieces);
+        assert_eq!(4, text.len());
+    }
+
+    #[test]
+    #[cfg(all(feature = "itoa", feature = "ryu"))]
+    pub fn test_push_numbers() {
+        let mut builder = TextBuilder::new();
+        builder.push_int(0u8);
+        builder.push_float(f64::NAN);
+        builder.push_float(f32::NEG_INFINITY);
+        builder.push_float(1e21f64);
+        let (_, pieces) = builder.build_shared();
+        let pieces: Vec<&str> = pieces.iter().map(Text::as_str).collect();
+        assert_eq!(vec!["0", "NaN", "-inf", "1e21"], pieces);
+    }
+
+    #[test]
+    pub fn test_limit() {
+        let mut builder = TextBuilder::with_limit(3);
+        assert_eq!(Some(3), builder.limit());
+        builder.push('ä');
+        assert!(builder.try_push('ö').is_err());
+        assert!(write!(builder, "{}", 12).is_err());
+        builder.try_push_text(&Text::new("x")).unwrap();
+        let err = builder.try_push_str("y").unwrap_err();
+        assert_eq!((3, 4), (err.limit(), err.attempted_len()));
+        assert_eq!(2, builder.pieces_len());
+        assert_eq!("äx", builder.build().as_str());
+        assert_eq!(None, TextBuilder::new().limit());
+    }
+
+    #[test]
+    pub fn test_write_fmt() {
+        let mut builder = TextBuilder::with_limit(4);
+        let (short, long) = ("ab", "cde");
+        assert!(write!(builder, "{}{}", short, long).is_err());
+        assert!(builder.is_empty());
+        assert_eq!(0, builder.pieces_len());
+        write!(builder, "{}-{}", 1, 2).unwrap();
+        assert_eq!(1, builder.pieces_len());
+        // The first fragment still fits, the second does not
+        let one = "x";
+        assert!(write!(builder, "{}{}", one, one).is_err());
+        let (text, pieces) = builder.build_shared();
+        assert_eq!("1-2", text.as_str());
+        assert_eq!(vec![text], pieces);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_limit_panic() {
+        TextBuilder::with_limit(0).push_str("a");
+    }
+
+    #[test]
+    pub fn test_concat_and_lift() {
+        let source = Text::new("one two three");
+        let parts: Vec<Text> = source.split_terminator_char(' ').collect();
+        let (arena, lifted) = Text::concat_and_lift(&parts);
+        assert_eq!("onetwothree", arena.as_str());
+        assert_eq!(parts, lifted);
+        for part in &lifted {
+            assert!(arena.try_lift_slice(part.as_str()).is_some());
+        }
+        let (empty, none) = Text::concat_and_lift(&[]);
+        assert!(empty.is_empty());
+        assert!(none.is_empty());
+    }
+}
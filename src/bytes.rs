@@ -0,0 +1,476 @@
+use crate::shared::IString;
+use crate::{check_len, from_offset, to_offset, SliceError, Text, TextData};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+/// The error returned when converting [`Bytes`] that are not valid UTF-8 into a [`Text`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidUtf8Error {
+    bytes: Bytes,
+    valid_up_to: usize,
+    error_len: Option<usize>,
+}
+
+impl InvalidUtf8Error {
+    /// Gets the index up to which the [`Bytes`] are valid UTF-8, see [`Bytes::valid_prefix`].
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Gets the range of the first invalid byte sequence.
+    /// If the [`Bytes`] end with an incomplete sequence, which might be completed by more data, it extends to the end.
+    pub fn invalid_range(&self) -> Range<usize> {
+        let len = self
+            .error_len
+            .unwrap_or(self.bytes.len() - self.valid_up_to);
+        self.valid_up_to..self.valid_up_to + len
+    }
+
+    /// Do the [`Bytes`] merely end with an incomplete sequence, instead of containing an invalid one?
+    pub fn is_incomplete(&self) -> bool {
+        self.error_len.is_none()
+    }
+
+    /// Gets back the rejected [`Bytes`].
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl Display for InvalidUtf8Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let range = self.invalid_range();
+        write!(
+            f,
+            "Invalid UTF-8 sequence at bytes {} to {}",
+            range.start, range.end
+        )
+    }
+}
+
+impl std::error::Error for InvalidUtf8Error {}
+
+/// A byte-level sibling of [`Text`] for data that is not (or not known to be) valid UTF-8, e.g. network frames.
+/// Like [`Text`], it is a slice of a shared buffer, so cloning and slicing never copy.
+///
+/// # Example
+/// ```
+/// use quetta::Bytes;
+///
+/// let frame = Bytes::new(b"\x02len\x00\xff\x03");
+/// let payload = frame.slice(1, 6);
+/// assert_eq!(b"len\x00\xff", payload.as_bytes());
+/// assert_eq!(frame.as_bytes()[1..].as_ptr(), payload.as_bytes().as_ptr());
+/// ```
+#[derive(Clone)]
+pub struct Bytes {
+    data: TextData,
+    /// Is the whole backing buffer known to be valid UTF-8, so that valid slices can become [`Text`]s without copying?
+    utf8: bool,
+}
+
+impl Bytes {
+    /// Creates new [`Bytes`] by copying the provided slice.
+    /// Will panic if the slice is longer than [`Text::MAX_LEN`].
+    pub fn new(bytes: &[u8]) -> Self {
+        if let Err(e) = check_len(bytes.len()) {
+            panic!("{}", e);
+        }
+        Self {
+            data: TextData {
+                string: IString::new_bytes(bytes),
+                start: 0,
+                len: to_offset(bytes.len()),
+            },
+            utf8: std::str::from_utf8(bytes).is_ok(),
+        }
+    }
+
+    /// Gets the bytes as a slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        let TextData { string, start, len } = &self.data;
+        let start = from_offset(*start);
+        &string.as_bytes()[start..start + from_offset(*len)]
+    }
+
+    /// Gets the length in bytes.
+    pub fn len(&self) -> usize {
+        from_offset(self.data.len)
+    }
+
+    /// Are these [`Bytes`] empty?
+    pub fn is_empty(&self) -> bool {
+        self.data.len == 0
+    }
+
+    /// Creates other [`Bytes`] with a provided start and end index, similar to the slice operator.
+    /// Will panic if the slice exceeds the bounds or ends before it starts.
+    pub fn slice(&self, start: usize, end: usize) -> Bytes {
+        match self.try_slice(start, end) {
+            Ok(bytes) => bytes,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Creates other [`Bytes`] with a provided start and end index, similar to the slice operator.
+    /// Returns an error if the slice exceeds the bounds or ends before it starts.
+    pub fn try_slice(&self, start: usize, end: usize) -> Result<Bytes, SliceError> {
+        if end < start {
+            return Err(SliceError::InvertedRange { start, end });
+        }
+        if end > self.len() {
+            return Err(SliceError::OutOfBounds {
+                text_len: self.len(),
+                start,
+                len: end - start,
+            });
+        }
+        Ok(self.slice_raw(start, end))
+    }
+
+    fn slice_raw(&self, start: usize, end: usize) -> Bytes {
+        Self {
+            data: TextData {
+                string: self.data.string.clone(),
+                start: to_offset(from_offset(self.data.start) + start),
+                len: to_offset(end - start),
+            },
+            utf8: self.utf8,
+        }
+    }
+
+    /// Iterates over all overlapping windows of `size` bytes, like [`slice::windows`].
+    /// Will panic if `size` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Bytes;
+    ///
+    /// let windows: Vec<Bytes> = Bytes::new(b"abc").windows(2).collect();
+    /// assert_eq!(vec![Bytes::new(b"ab"), Bytes::new(b"bc")], windows);
+    /// ```
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = Bytes> + '_ {
+        assert!(size != 0, "Window size must not be 0");
+        (0..(self.len() + 1).saturating_sub(size))
+            .map(move |start| self.slice_raw(start, start + size))
+    }
+
+    /// Iterates over consecutive chunks of `size` bytes, like [`slice::chunks`]. The last chunk may be shorter.
+    /// Will panic if `size` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Bytes;
+    ///
+    /// let chunks: Vec<Bytes> = Bytes::new(b"abcde").chunks(2).collect();
+    /// assert_eq!(vec![Bytes::new(b"ab"), Bytes::new(b"cd"), Bytes::new(b"e")], chunks);
+    /// ```
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Bytes> + '_ {
+        assert!(size != 0, "Chunk size must not be 0");
+        (0..self.len())
+            .step_by(size)
+            .map(move |start| self.slice_raw(start, self.len().min(start + size)))
+    }
+
+    /// Splits at every byte matching `pred`, which is not included in the pieces, like [`slice::split`].
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Bytes;
+    ///
+    /// let fields: Vec<Bytes> = Bytes::new(b"a\0\0b").split(|b| *b == 0).collect();
+    /// assert_eq!(vec![Bytes::new(b"a"), Bytes::new(b""), Bytes::new(b"b")], fields);
+    /// ```
+    pub fn split<'a, F: FnMut(&u8) -> bool + 'a>(
+        &'a self,
+        mut pred: F,
+    ) -> impl Iterator<Item = Bytes> + 'a {
+        let ends = self
+            .as_bytes()
+            .iter()
+            .enumerate()
+            .filter(move |(_, b)| pred(b))
+            .map(|(i, _)| i)
+            .chain(std::iter::once(self.len()));
+        ends.scan(0, move |start, end| {
+            let piece = self.slice_raw(*start, end);
+            *start = end + 1;
+            Some(piece)
+        })
+    }
+
+    /// Finds the first occurrence of `needle`, returning its start index.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Bytes;
+    ///
+    /// let frame = Bytes::new(b"HEAD\r\n\r\nbody");
+    /// let end = frame.find(b"\r\n\r\n").unwrap();
+    /// assert_eq!(b"body", frame.slice(end + 4, frame.len()).as_bytes());
+    /// ```
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        memchr::memmem::find(self.as_bytes(), needle)
+    }
+
+    /// Splits the [`Bytes`] into the longest prefix that is valid UTF-8 and the rest,
+    /// e.g. to keep an incomplete sequence at the end of a network read until more data arrives.
+    /// The prefix shares the buffer if it is known to be valid UTF-8, see [`Text::try_from`].
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Bytes;
+    ///
+    /// let (text, rest) = Bytes::new(b"ab\xc3").valid_prefix();
+    /// assert_eq!("ab", text.as_str());
+    /// assert_eq!(b"\xc3", rest.as_bytes());
+    /// ```
+    pub fn valid_prefix(&self) -> (Text, Bytes) {
+        let valid_up_to = match std::str::from_utf8(self.as_bytes()) {
+            Ok(_) => self.len(),
+            Err(e) => e.valid_up_to(),
+        };
+        let prefix = self.slice_raw(0, valid_up_to).into_text_unchecked();
+        (prefix, self.slice_raw(valid_up_to, self.len()))
+    }
+
+    /// Converts [`Bytes`] known to be valid UTF-8 into a [`Text`], sharing the buffer only if all of it is known to be valid UTF-8,
+    /// as a [`Text`] may access its whole backing buffer as a `str`. Otherwise, only the slice is copied.
+    fn into_text_unchecked(self) -> Text {
+        if self.utf8 {
+            return Text(self.data);
+        }
+        // SAFETY: The caller guarantees that the slice itself is valid UTF-8.
+        Text::new(unsafe { std::str::from_utf8_unchecked(self.as_bytes()) })
+    }
+}
+
+impl Text {
+    /// Views the [`Text`] as [`Bytes`] sharing the same buffer, without copying.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("añb");
+    /// assert_eq!(3, text.to_bytes().windows(2).count());
+    /// ```
+    pub fn to_bytes(&self) -> Bytes {
+        Bytes {
+            data: self.0.clone(),
+            utf8: true,
+        }
+    }
+
+    /// Decodes `bytes` as UTF-8, replacing every invalid sequence with `U+FFFD` like [`String::from_utf8_lossy`],
+    /// and also returns the ranges of `bytes` that were replaced, e.g. to log data quality metrics during ingestion.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let (text, replaced) = Text::from_utf8_sanitized(b"caf\xe9 ok\xff");
+    /// assert_eq!("caf\u{FFFD} ok\u{FFFD}", text.as_str());
+    /// assert_eq!(vec![3..4, 7..8], replaced);
+    /// ```
+    pub fn from_utf8_sanitized(bytes: &[u8]) -> (Text, Vec<Range<usize>>) {
+        let mut decoded = String::with_capacity(bytes.len());
+        let mut replaced = Vec::new();
+        let mut offset = 0;
+        loop {
+            let rest = &bytes[offset..];
+            match std::str::from_utf8(rest) {
+                Ok(valid) => {
+                    decoded.push_str(valid);
+                    break;
+                }
+                Err(e) => {
+                    let valid = e.valid_up_to();
+                    // SAFETY: The bytes up to `valid_up_to` were just validated.
+                    decoded.push_str(unsafe { std::str::from_utf8_unchecked(&rest[..valid]) });
+                    decoded.push('\u{FFFD}');
+                    let start = offset + valid;
+                    offset = start + e.error_len().unwrap_or(rest.len() - valid);
+                    replaced.push(start..offset);
+                }
+            }
+        }
+        (Text::from_owner(decoded), replaced)
+    }
+}
+
+impl TryFrom<Bytes> for Text {
+    type Error = InvalidUtf8Error;
+
+    /// Converts [`Bytes`] into a [`Text`], returning an error with the position of the first invalid sequence
+    /// if they are not valid UTF-8. Only the slice is validated. The buffer is shared if all of it was found to be valid UTF-8
+    /// when the [`Bytes`] were created, e.g. by [`Text::to_bytes`]; otherwise, the slice is copied.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{Bytes, Text};
+    /// use std::convert::TryFrom;
+    ///
+    /// let text = Text::new("añb");
+    /// let roundtrip = Text::try_from(text.to_bytes()).unwrap();
+    /// assert_eq!(text.as_str().as_ptr(), roundtrip.as_str().as_ptr());
+    ///
+    /// let err = Text::try_from(Bytes::new(b"a\xffb")).unwrap_err();
+    /// assert_eq!(1..2, err.invalid_range());
+    /// ```
+    fn try_from(bytes: Bytes) -> Result<Self, Self::Error> {
+        match std::str::from_utf8(bytes.as_bytes()) {
+            Ok(_) => Ok(bytes.into_text_unchecked()),
+            Err(e) => Err(InvalidUtf8Error {
+                valid_up_to: e.valid_up_to(),
+                error_len: e.error_len(),
+                bytes,
+            }),
+        }
+    }
+}
+
+impl Default for Bytes {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl Debug for Bytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "b\"")?;
+        for b in self.as_bytes() {
+            write!(f, "{}", std::ascii::escape_default(*b))?;
+        }
+        write!(f, "\"")
+    }
+}
+
+impl PartialEq for Bytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for Bytes {}
+
+impl Hash for Bytes {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state)
+    }
+}
+
+impl PartialOrd for Bytes {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Bytes {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl<'a> From<&'a [u8]> for Bytes {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl From<Text> for Bytes {
+    fn from(text: Text) -> Self {
+        Self {
+            data: text.0,
+            utf8: true,
+        }
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<[u8]> for Bytes {
+    fn borrow(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bytes, SliceError, Text};
+    use std::convert::TryFrom;
+
+    #[test]
+    pub fn test_slicing() {
+        let b = Bytes::new(b"\xffab\x00");
+        assert_eq!(b"ab", b.slice(1, 3).as_bytes());
+        assert_eq!(b"b", b.slice(1, 3).slice(1, 2).as_bytes());
+        let (start, end) = (3, 1);
+        assert_eq!(
+            Err(SliceError::InvertedRange { start, end }),
+            b.try_slice(start, end)
+        );
+        assert!(b.try_slice(2, 5).is_err());
+        assert_eq!("b\"\\xffab\\x00\"", format!("{:?}", b));
+        let text = Text::new("xañ").slice(1, 4);
+        assert_eq!("añ".as_bytes(), text.to_bytes().as_bytes());
+    }
+
+    #[test]
+    pub fn test_iterators() {
+        let b = Bytes::new(b"xabc").slice(1, 4);
+        assert_eq!(0, b.windows(4).count());
+        assert_eq!(1, b.windows(3).count());
+        assert_eq!(3, b.chunks(1).count());
+        assert_eq!(0, Bytes::default().chunks(1).count());
+        let parts: Vec<Bytes> = b.split(|c| *c != b'b').collect();
+        assert_eq!(3, parts.len());
+        assert!(parts.iter().all(|p| p.len() <= 1));
+        assert_eq!(1, Bytes::default().split(|_| true).count());
+        assert_eq!(Some(1), b.find(b"bc"));
+        assert_eq!(None, b.find(b"x"));
+    }
+
+    #[test]
+    pub fn test_try_from() {
+        let b = Bytes::new(b"a\xe2\x82\xacb\xe2\x82");
+        let euro = Text::try_from(b.slice(1, 4)).unwrap();
+        assert_eq!("€", euro.as_str());
+        assert_eq!(3, euro.backing_len());
+        let err = Text::try_from(b.clone()).unwrap_err();
+        assert_eq!(5, err.valid_up_to());
+        assert_eq!(5..7, err.invalid_range());
+        assert!(err.is_incomplete());
+        assert_eq!(b, err.into_bytes());
+        let err = Text::try_from(Bytes::new(b"\xe2\x82a")).unwrap_err();
+        assert_eq!(0..2, err.invalid_range());
+        assert!(!err.is_incomplete());
+        let (prefix, rest) = b.slice(1, 7).valid_prefix();
+        assert_eq!(("€b", &b"\xe2\x82"[..]), (prefix.as_str(), rest.as_bytes()));
+        let text = Text::new("xañ").slice(1, 4);
+        let shared = Text::try_from(text.to_bytes()).unwrap();
+        assert_eq!(text.as_str().as_ptr(), shared.as_str().as_ptr());
+        let valid = Bytes::new(b"abc");
+        assert_eq!(3, Text::try_from(valid.slice(1, 2)).unwrap().backing_len());
+        assert_eq!(4, b.slice(0, 4).valid_prefix().0.backing_len());
+    }
+
+    #[test]
+    pub fn test_from_utf8_sanitized() {
+        let input = b"\xe2\x82a\xc3\xa4\xed\xa0\x80\xf0\x9f";
+        let (text, replaced) = Text::from_utf8_sanitized(input);
+        assert_eq!(String::from_utf8_lossy(input), text.as_str());
+        assert_eq!(vec![0..2, 5..6, 6..7, 7..8, 8..10], replaced);
+        let (text, replaced) = Text::from_utf8_sanitized("añb".as_bytes());
+        assert_eq!("añb", text.as_str());
+        assert!(replaced.is_empty());
+    }
+}
@@ -0,0 +1,267 @@
+use crate::Text;
+
+/// An identifier casing convention, see [`Text::to_case`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `Title Case`
+    Title,
+}
+
+/// Splits an identifier into words at separators (any non-alphanumeric character)
+/// and at case changes (`fooBar`, `HTTPServer`).
+fn words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in s.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (pos, c) = chars[i];
+            let prev = chars[i - 1].1;
+            let next_lower = chars.get(i + 1).is_some_and(|(_, n)| n.is_lowercase());
+            let boundary = c.is_uppercase()
+                && (prev.is_lowercase() || prev.is_numeric() || prev.is_uppercase() && next_lower);
+            if boundary {
+                words.push(&part[start..pos]);
+                start = pos;
+            }
+        }
+        if start < part.len() {
+            words.push(&part[start..]);
+        }
+    }
+    words
+}
+
+fn push_capitalized(out: &mut String, word: &str) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        out.extend(first.to_uppercase());
+        out.push_str(&chars.as_str().to_lowercase());
+    }
+}
+
+fn convert(s: &str, case: Case) -> String {
+    let mut out = String::with_capacity(s.len() + 4);
+    for (i, word) in words(s).into_iter().enumerate() {
+        match case {
+            Case::Snake | Case::ScreamingSnake if i > 0 => out.push('_'),
+            Case::Kebab if i > 0 => out.push('-'),
+            Case::Title if i > 0 => out.push(' '),
+            _ => (),
+        }
+        match case {
+            Case::Snake | Case::Kebab => out.push_str(&word.to_lowercase()),
+            Case::ScreamingSnake => out.push_str(&word.to_uppercase()),
+            Case::Camel if i == 0 => out.push_str(&word.to_lowercase()),
+            Case::Camel | Case::Pascal | Case::Title => push_capitalized(&mut out, word),
+        }
+    }
+    out
+}
+
+impl Text {
+    /// Converts an identifier to the given [`Case`]. Words are split at non-alphanumeric characters
+    /// and at case changes. If the [`Text`] is already in the target case, `self` is returned without copying.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{Case, Text};
+    ///
+    /// let name = Text::new("parseHTTPRequest");
+    /// assert_eq!("parse_http_request", name.to_case(Case::Snake).as_str());
+    /// assert_eq!("ParseHttpRequest", name.to_case(Case::Pascal).as_str());
+    /// ```
+    pub fn to_case(&self, case: Case) -> Text {
+        let converted = convert(self.as_str(), case);
+        if converted == self.as_str() {
+            self.clone()
+        } else {
+            Text::new(converted.as_str())
+        }
+    }
+
+    /// Is the [`Text`] already in the given [`Case`]?
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{Case, Text};
+    ///
+    /// assert!(Text::new("max-len").is_case(Case::Kebab));
+    /// assert!(!Text::new("max_len").is_case(Case::Kebab));
+    /// ```
+    pub fn is_case(&self, case: Case) -> bool {
+        convert(self.as_str(), case) == self.as_str()
+    }
+
+    /// Converts the [`Text`] into a URL slug: runs of ASCII letters and digits, lowercased and joined by `separator`.
+    /// All other `char`s separate words and are dropped, so use `Text::to_ascii_transliterated` (with the `deunicode` feature) first to keep accented letters.
+    /// If the [`Text`] already is such a slug, `self` is returned without copying.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let title = Text::new("  Rust 2024: What's New?");
+    /// assert_eq!("rust-2024-what-s-new", title.slugify('-').as_str());
+    /// ```
+    pub fn slugify(&self, separator: char) -> Text {
+        let s = self.as_str();
+        let mut slug = String::with_capacity(s.len());
+        for word in s
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+        {
+            if !slug.is_empty() {
+                slug.push(separator);
+            }
+            slug.extend(word.chars().map(|c| c.to_ascii_lowercase()));
+        }
+        if slug == s {
+            self.clone()
+        } else {
+            Text::new(slug.as_str())
+        }
+    }
+
+    /// Converts the [`Text`] into an identifier in the given [`Case`] like [`Text::to_case`], which also drops all
+    /// non-alphanumeric `char`s, and prefixes it with `_` if it would be empty or start with a digit.
+    /// As their separators are not allowed in identifiers, [`Case::Kebab`] is treated as [`Case::Snake`]
+    /// and [`Case::Title`] as [`Case::Pascal`].
+    /// If the [`Text`] already is such an identifier, `self` is returned without copying.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{Case, Text};
+    ///
+    /// assert_eq!("_2fa_enabled", Text::new("2fa enabled?").sanitize_identifier(Case::Snake).as_str());
+    /// assert_eq!("userId", Text::new("user id").sanitize_identifier(Case::Camel).as_str());
+    /// assert_eq!("user_id", Text::new("user id").sanitize_identifier(Case::Kebab).as_str());
+    /// ```
+    pub fn sanitize_identifier(&self, case: Case) -> Text {
+        let case = match case {
+            Case::Kebab => Case::Snake,
+            Case::Title => Case::Pascal,
+            case => case,
+        };
+        let converted = self.to_case(case);
+        match converted.as_str().chars().next() {
+            Some(first) if !first.is_numeric() => converted,
+            _ => Text::new(format!("_{}", converted).as_str()),
+        }
+    }
+
+    /// Converts to `snake_case`, see [`Text::to_case`].
+    pub fn to_snake_case(&self) -> Text {
+        self.to_case(Case::Snake)
+    }
+
+    /// Converts to `SCREAMING_SNAKE_CASE`, see [`Text::to_case`].
+    pub fn to_screaming_snake_case(&self) -> Text {
+        self.to_case(Case::ScreamingSnake)
+    }
+
+    /// Converts to `kebab-case`, see [`Text::to_case`].
+    pub fn to_kebab_case(&self) -> Text {
+        self.to_case(Case::Kebab)
+    }
+
+    /// Converts to `camelCase`, see [`Text::to_case`].
+    pub fn to_camel_case(&self) -> Text {
+        self.to_case(Case::Camel)
+    }
+
+    /// Converts to `PascalCase`, see [`Text::to_case`].
+    pub fn to_pascal_case(&self) -> Text {
+        self.to_case(Case::Pascal)
+    }
+
+    /// Converts to `Title Case`, see [`Text::to_case`].
+    pub fn to_title_case(&self) -> Text {
+        self.to_case(Case::Title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Case, Text};
+
+    #[test]
+    pub fn test_conversions() {
+        let t = Text::new("XMLHttpRequest2_body-size");
+        assert_eq!("xml_http_request2_body_size", t.to_snake_case().as_str());
+        assert_eq!(
+            "XML_HTTP_REQUEST2_BODY_SIZE",
+            t.to_screaming_snake_case().as_str()
+        );
+        assert_eq!("xml-http-request2-body-size", t.to_kebab_case().as_str());
+        assert_eq!("xmlHttpRequest2BodySize", t.to_camel_case().as_str());
+        assert_eq!("XmlHttpRequest2BodySize", t.to_pascal_case().as_str());
+        assert_eq!("Xml Http Request2 Body Size", t.to_title_case().as_str());
+        assert_eq!(
+            "version2_final",
+            Text::new("version2Final").to_snake_case().as_str()
+        );
+    }
+
+    #[test]
+    pub fn test_slugify() {
+        let slug = Text::new("xa-1x").slice(1, 4);
+        assert_eq!(slug.as_str().as_ptr(), slug.slugify('-').as_str().as_ptr());
+        assert_eq!("a_1", slug.slugify('_').as_str());
+        assert_eq!("gr-e", Text::new("-Grüße-").slugify('-').as_str());
+        assert_eq!("", Text::new("?!").slugify('-').as_str());
+    }
+
+    #[test]
+    pub fn test_sanitize_identifier() {
+        let ident = Text::new("max_len");
+        let sanitized = ident.sanitize_identifier(Case::Snake);
+        assert_eq!(ident.as_str().as_ptr(), sanitized.as_str().as_ptr());
+        assert_eq!(
+            "_",
+            Text::new("--").sanitize_identifier(Case::Pascal).as_str()
+        );
+        assert_eq!(
+            "_3d_model",
+            Text::new("3d model")
+                .sanitize_identifier(Case::Snake)
+                .as_str()
+        );
+        assert_eq!(
+            "Größe",
+            Text::new("größe")
+                .sanitize_identifier(Case::Pascal)
+                .as_str()
+        );
+        let t = Text::new("max len");
+        assert_eq!("max_len", t.sanitize_identifier(Case::Kebab).as_str());
+        assert_eq!("MaxLen", t.sanitize_identifier(Case::Title).as_str());
+    }
+
+    #[test]
+    pub fn test_already_in_case() {
+        for (s, case) in [
+            ("a_b", Case::Snake),
+            ("A_B", Case::ScreamingSnake),
+            ("a-b", Case::Kebab),
+            ("aB", Case::Camel),
+            ("AbC", Case::Pascal),
+            ("A B", Case::Title),
+        ] {
+            let t = Text::new(s);
+            assert!(t.is_case(case), "{} should be {:?}", s, case);
+            assert_eq!(t.as_str().as_ptr(), t.to_case(case).as_str().as_ptr());
+        }
+        assert_eq!("", Text::default().to_camel_case().as_str());
+    }
+}
@@ -0,0 +1,455 @@
+use crate::Text;
+use std::ops::{Deref, Range, RangeFrom, RangeFull, RangeTo};
+
+/// Number of chars between two checkpoints in a [`CharIndexedText`].
+const CHECKPOINT_STRIDE: usize = 32;
+
+/// A range of `char` positions for [`Text::slice_chars`], where negative positions count from the end like in Python.
+/// Created from ranges of `isize`, e.g. `-3..` for the last three `char`s or `1..-1` for all but the first and last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CharRange {
+    start: isize,
+    end: Option<isize>,
+}
+
+impl From<Range<isize>> for CharRange {
+    fn from(range: Range<isize>) -> Self {
+        Self {
+            start: range.start,
+            end: Some(range.end),
+        }
+    }
+}
+
+impl From<RangeFrom<isize>> for CharRange {
+    fn from(range: RangeFrom<isize>) -> Self {
+        Self {
+            start: range.start,
+            end: None,
+        }
+    }
+}
+
+impl From<RangeTo<isize>> for CharRange {
+    fn from(range: RangeTo<isize>) -> Self {
+        Self {
+            start: 0,
+            end: Some(range.end),
+        }
+    }
+}
+
+impl From<RangeFull> for CharRange {
+    fn from(_: RangeFull) -> Self {
+        Self {
+            start: 0,
+            end: None,
+        }
+    }
+}
+
+impl Text {
+    /// Counts the `char`s in the [`Text`]. This scans the whole [`Text`];
+    /// use [`Text::char_indexed`] when the count is needed repeatedly.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("Grüße");
+    /// assert_eq!(5, text.char_len());
+    /// assert_eq!(7, text.len());
+    /// ```
+    pub fn char_len(&self) -> usize {
+        self.as_str().chars().count()
+    }
+
+    /// Gets the only `char` of the [`Text`], or `None` if it is empty or has more than one `char`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!(Some('ß'), Text::new("ß").as_char());
+    /// assert_eq!(None, Text::new("ss").as_char());
+    /// ```
+    pub fn as_char(&self) -> Option<char> {
+        let bytes = self.as_str().as_bytes();
+        match bytes {
+            [b] if b.is_ascii() => Some(*b as char),
+            _ if bytes.is_empty() || bytes.len() > 4 => None,
+            _ => {
+                let mut chars = self.as_str().chars();
+                let c = chars.next()?;
+                chars.next().is_none().then_some(c)
+            }
+        }
+    }
+
+    /// Is the [`Text`] exactly the single `char` `c`? This compares bytes without decoding,
+    /// which is cheaper than comparing with a string for the many one-`char` tokens of typical token streams.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert!(Text::new("{").eq_char('{'));
+    /// assert!(!Text::new("{{").eq_char('{'));
+    /// ```
+    pub fn eq_char(&self, c: char) -> bool {
+        self.as_str().as_bytes() == c.encode_utf8(&mut [0; 4]).as_bytes()
+    }
+
+    /// Does the [`Text`] contain the `char` `c`? Searches with `memchr` for ASCII `char`s.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert!(Text::new("key=value").contains_char('='));
+    /// assert!(Text::new("Grüße").contains_char('ü'));
+    /// ```
+    pub fn contains_char(&self, c: char) -> bool {
+        if c.is_ascii() {
+            memchr::memchr(c as u8, self.as_str().as_bytes()).is_some()
+        } else {
+            self.as_str().contains(c)
+        }
+    }
+
+    /// Gets the byte offset of the `n`th `char` in the [`Text`].
+    /// For `n == self.char_len()`, this is the length of the [`Text`], so the result can be used as an exclusive end.
+    /// Returns `None` if `n` is larger than that.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("Grüße");
+    /// assert_eq!(Some(4), text.nth_char_offset(3));
+    /// assert_eq!(Some(7), text.nth_char_offset(5));
+    /// assert_eq!(None, text.nth_char_offset(6));
+    /// ```
+    pub fn nth_char_offset(&self, n: usize) -> Option<usize> {
+        let s = self.as_str();
+        s.char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(s.len()))
+            .nth(n)
+    }
+
+    /// Slices the [`Text`] by `char` positions, where negative positions count from the end.
+    /// Returns `None` if a position lies outside of the [`Text`] or the range is inverted.
+    /// The [`Text`] is only scanned for its `char` length if a position is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("Grüße");
+    /// assert_eq!("ße", text.checked_slice_chars(-2..).unwrap().as_str());
+    /// assert_eq!("rüß", text.checked_slice_chars(1..-1).unwrap().as_str());
+    /// assert_eq!(None, text.checked_slice_chars(..-6));
+    /// ```
+    pub fn checked_slice_chars<R: Into<CharRange>>(&self, range: R) -> Option<Text> {
+        let range = range.into();
+        let char_len = if range.start < 0 || range.end.is_some_and(|end| end < 0) {
+            Some(self.char_len())
+        } else {
+            None
+        };
+        let resolve = |position: isize| -> Option<usize> {
+            if position >= 0 {
+                Some(position as usize)
+            } else {
+                char_len?.checked_sub(position.unsigned_abs())
+            }
+        };
+        let start = resolve(range.start)?;
+        let start_offset = self.nth_char_offset(start)?;
+        let end_offset = match range.end {
+            None => self.len(),
+            Some(end) => {
+                let end = resolve(end)?;
+                if end < start {
+                    return None;
+                }
+                start_offset
+                    + self.as_str()[start_offset..]
+                        .char_indices()
+                        .map(|(i, _)| i)
+                        .chain(std::iter::once(self.len() - start_offset))
+                        .nth(end - start)?
+            }
+        };
+        Some(self.slice(start_offset, end_offset))
+    }
+
+    /// Slices the [`Text`] by `char` positions like [`Text::checked_slice_chars`],
+    /// but will panic if a position lies outside of the [`Text`] or the range is inverted.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!("üße", Text::new("Grüße").slice_chars(-3..).as_str());
+    /// ```
+    pub fn slice_chars<R: Into<CharRange>>(&self, range: R) -> Text {
+        let range = range.into();
+        match self.checked_slice_chars(range) {
+            Some(text) => text,
+            None => panic!(
+                "Char range {:?} out of bounds for string of length {}",
+                range,
+                self.len()
+            ),
+        }
+    }
+
+    /// Creates a [`CharIndexedText`], which scans the [`Text`] once and then answers
+    /// `char`-based length and offset queries without rescanning.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let indexed = Text::new("añb").char_indexed();
+    /// assert_eq!(3, indexed.char_len());
+    /// assert_eq!(Some(3), indexed.nth_char_offset(2));
+    /// ```
+    pub fn char_indexed(&self) -> CharIndexedText {
+        CharIndexedText::new(self.clone())
+    }
+
+    /// Splits the [`Text`] after the longest prefix whose `char`s all satisfy `pred`
+    /// (`span` in parser combinator terms), returning the prefix and the rest as slices of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let input = Text::new("42px");
+    /// let (number, unit) = input.split_prefix_while(|c| c.is_ascii_digit());
+    /// assert_eq!("42", number.as_str());
+    /// assert_eq!("px", unit.as_str());
+    /// ```
+    pub fn split_prefix_while<P: FnMut(char) -> bool>(&self, mut pred: P) -> (Text, Text) {
+        let s = self.as_str();
+        let end = s.find(|c| !pred(c)).unwrap_or(s.len());
+        (self.slice(0, end), self.slice(end, s.len()))
+    }
+
+    /// Splits the [`Text`] into runs of consecutive `char`s, like `slice::chunk_by`:
+    /// a run continues as long as `pred` returns `true` for each pair of adjacent `char`s.
+    /// The runs are slices of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("ab12+3");
+    /// let runs: Vec<Text> = text.chunk_by(|a, b| a.is_ascii_digit() == b.is_ascii_digit()).collect();
+    /// assert_eq!(4, runs.len());
+    /// assert_eq!("12", runs[1].as_str());
+    /// assert_eq!("+", runs[2].as_str());
+    /// ```
+    pub fn chunk_by<'a, P: FnMut(char, char) -> bool + 'a>(
+        &'a self,
+        mut pred: P,
+    ) -> impl Iterator<Item = Text> + 'a {
+        let mut start = 0;
+        std::iter::from_fn(move || {
+            let rest = &self.as_str()[start..];
+            let mut chars = rest.char_indices();
+            let (_, mut previous) = chars.next()?;
+            let len = chars
+                .find(|(_, c)| !pred(std::mem::replace(&mut previous, *c), *c))
+                .map_or(rest.len(), |(i, _)| i);
+            let run = self.substring(start, len);
+            start += len;
+            Some(run)
+        })
+    }
+}
+
+/// A [`Text`] with a precomputed `char` count and an index of `char` offsets,
+/// making `char`-based access cheap. Dereferences to the underlying [`Text`].
+#[derive(Debug, Clone)]
+pub struct CharIndexedText {
+    text: Text,
+    char_len: usize,
+    /// Byte offsets of every `CHECKPOINT_STRIDE`th char. Empty if the text is ASCII.
+    checkpoints: Vec<usize>,
+}
+
+impl CharIndexedText {
+    /// Indexes `text`.
+    pub fn new(text: Text) -> Self {
+        let s = text.as_str();
+        if s.is_ascii() {
+            return Self {
+                char_len: s.len(),
+                text,
+                checkpoints: Vec::new(),
+            };
+        }
+        let mut char_len = 0;
+        let mut checkpoints = Vec::new();
+        for (i, _) in s.char_indices() {
+            if char_len % CHECKPOINT_STRIDE == 0 {
+                checkpoints.push(i);
+            }
+            char_len += 1;
+        }
+        Self {
+            text,
+            char_len,
+            checkpoints,
+        }
+    }
+
+    /// Gets the underlying [`Text`].
+    pub fn text(&self) -> &Text {
+        &self.text
+    }
+
+    /// Gets the number of `char`s, in O(1).
+    pub fn char_len(&self) -> usize {
+        self.char_len
+    }
+
+    /// Gets the byte offset of the `n`th `char`, like [`Text::nth_char_offset`],
+    /// but without scanning from the start of the [`Text`].
+    pub fn nth_char_offset(&self, n: usize) -> Option<usize> {
+        if n > self.char_len {
+            return None;
+        }
+        if n == self.char_len {
+            return Some(self.text.len());
+        }
+        if self.checkpoints.is_empty() {
+            return Some(n);
+        }
+        let start = self.checkpoints[n / CHECKPOINT_STRIDE];
+        self.text.as_str()[start..]
+            .char_indices()
+            .nth(n % CHECKPOINT_STRIDE)
+            .map(|(i, _)| start + i)
+    }
+
+    /// Slices the [`Text`] by `char` positions instead of byte offsets.
+    /// Will panic if `start > end` or `end > self.char_len()`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let indexed = Text::new("Grüße").char_indexed();
+    /// assert_eq!("üß", indexed.char_slice(2, 4).as_str());
+    /// ```
+    pub fn char_slice(&self, start: usize, end: usize) -> Text {
+        match (self.nth_char_offset(start), self.nth_char_offset(end)) {
+            (Some(s), Some(e)) => self.text.slice(s, e),
+            _ => panic!(
+                "Char index out of bounds: Char length of string is {}, but slice start was {} and slice end was {}",
+                self.char_len, start, end
+            ),
+        }
+    }
+
+    /// Unwraps the underlying [`Text`].
+    pub fn into_inner(self) -> Text {
+        self.text
+    }
+}
+
+impl Deref for CharIndexedText {
+    type Target = Text;
+
+    fn deref(&self) -> &Text {
+        &self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_char_indexed() {
+        let s: String = (0..200)
+            .map(|i| if i % 3 == 0 { 'é' } else { 'x' })
+            .collect();
+        let t = Text::new(s.as_str());
+        let indexed = t.char_indexed();
+        assert_eq!(t.char_len(), indexed.char_len());
+        for n in 0..=201 {
+            assert_eq!(t.nth_char_offset(n), indexed.nth_char_offset(n));
+        }
+        let ascii = Text::new("abc").char_indexed();
+        assert_eq!(Some(1), ascii.nth_char_offset(1));
+        assert_eq!(Some(3), ascii.nth_char_offset(3));
+        assert_eq!(None, ascii.nth_char_offset(4));
+        assert_eq!("bc", ascii.char_slice(1, 3).as_str());
+    }
+
+    #[test]
+    pub fn test_single_chars() {
+        let t = Text::new("xaäx").slice(1, 4);
+        assert_eq!(Some('a'), t.slice(0, 1).as_char());
+        assert_eq!(Some('ä'), t.slice(1, 3).as_char());
+        assert_eq!(None, t.as_char());
+        assert_eq!(None, Text::default().as_char());
+        assert_eq!(None, Text::new("abcde").as_char());
+        assert!(t.slice(1, 3).eq_char('ä'));
+        assert!(!t.eq_char('a'));
+        assert!(t.contains_char('a') && t.contains_char('ä'));
+        assert!(!t.contains_char('x') && !t.contains_char('ö'));
+    }
+
+    #[test]
+    pub fn test_checked_slice_chars() {
+        let t = Text::new("xañbx").slice(1, 5);
+        assert_eq!(Some(t.clone()), t.checked_slice_chars(..));
+        assert_eq!("ñ", t.checked_slice_chars(1..2).unwrap().as_str());
+        assert_eq!("ñ", t.checked_slice_chars(-2..-1).unwrap().as_str());
+        assert_eq!("añ", t.checked_slice_chars(..-1).unwrap().as_str());
+        assert_eq!("", t.checked_slice_chars(3..).unwrap().as_str());
+        assert_eq!("", t.checked_slice_chars(-1..2).unwrap().as_str());
+        assert_eq!(None, t.checked_slice_chars(4..));
+        assert_eq!(None, t.checked_slice_chars(-4..));
+        let (start, end) = (2, 1);
+        assert_eq!(None, t.checked_slice_chars(start..end));
+        assert_eq!(None, t.checked_slice_chars(0..4));
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_slice_chars_panic() {
+        Text::new("ab").slice_chars(..-3);
+    }
+
+    #[test]
+    pub fn test_split_prefix_while() {
+        let t = Text::new("xäöü1x").slice(1, 8);
+        let (letters, rest) = t.split_prefix_while(char::is_alphabetic);
+        assert_eq!(("äöü", "1"), (letters.as_str(), rest.as_str()));
+        let (all, none) = t.split_prefix_while(|_| true);
+        assert_eq!((t.as_str(), ""), (all.as_str(), none.as_str()));
+        let (none, all) = t.split_prefix_while(|_| false);
+        assert_eq!(("", t.as_str()), (none.as_str(), all.as_str()));
+    }
+
+    #[test]
+    pub fn test_chunk_by() {
+        let t = Text::new("xaaäää1").slice(1, 10);
+        let runs: Vec<String> = t.chunk_by(|a, b| a == b).map(|r| r.to_string()).collect();
+        assert_eq!(vec!["aa", "äää", "1"], runs);
+        let ascending: Vec<String> = Text::new("abcbcd")
+            .chunk_by(|a, b| a < b)
+            .map(|r| r.to_string())
+            .collect();
+        assert_eq!(vec!["abc", "bcd"], ascending);
+        assert_eq!(0, Text::default().chunk_by(|_, _| true).count());
+    }
+}
@@ -0,0 +1,175 @@
+use crate::Text;
+
+/// A compiled set of `char`s for fast scanning with [`Text::span_of_class`].
+/// ASCII characters are looked up in a bitset; other characters in a sorted list of ranges.
+///
+/// # Example
+/// ```
+/// use quetta::CharClass;
+///
+/// let mut hex = CharClass::ascii_digits();
+/// hex.add_range('a', 'f');
+/// hex.add_range('A', 'F');
+/// assert!(hex.contains('c'));
+/// assert!(!hex.contains('g'));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CharClass {
+    ascii: u128,
+    /// Sorted, non-overlapping and non-adjacent ranges of non-ASCII chars.
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharClass {
+    /// Creates an empty [`CharClass`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ASCII digits `0-9`.
+    pub fn ascii_digits() -> Self {
+        let mut class = Self::new();
+        class.add_range('0', '9');
+        class
+    }
+
+    /// The ASCII letters and digits.
+    pub fn ascii_alphanumeric() -> Self {
+        let mut class = Self::ascii_digits();
+        class.add_range('a', 'z');
+        class.add_range('A', 'Z');
+        class
+    }
+
+    /// ASCII letters, digits and `_`, the characters of a typical identifier.
+    pub fn ascii_identifier() -> Self {
+        let mut class = Self::ascii_alphanumeric();
+        class.add_char('_');
+        class
+    }
+
+    /// ASCII whitespace, as defined by `char::is_ascii_whitespace`.
+    pub fn ascii_whitespace() -> Self {
+        let mut class = Self::new();
+        class.add_chars(" \t\n\x0C\r");
+        class
+    }
+
+    /// Adds a single `char`.
+    pub fn add_char(&mut self, c: char) {
+        self.add_range(c, c)
+    }
+
+    /// Adds every `char` of `chars`.
+    pub fn add_chars(&mut self, chars: &str) {
+        for c in chars.chars() {
+            self.add_char(c)
+        }
+    }
+
+    /// Adds all `char`s from `from` to `to`, inclusive. Does nothing if `from > to`.
+    pub fn add_range(&mut self, from: char, to: char) {
+        let (mut from, to) = (from as u32, to as u32);
+        while from <= to && from < 128 {
+            self.ascii |= 1 << from;
+            from += 1;
+        }
+        if from > to {
+            return;
+        }
+        let index = self.ranges.partition_point(|(_, end)| end + 1 < from);
+        let mut merged = (from, to);
+        while let Some(&(start, end)) = self.ranges.get(index) {
+            if start > merged.1.saturating_add(1) {
+                break;
+            }
+            merged = (merged.0.min(start), merged.1.max(end));
+            self.ranges.remove(index);
+        }
+        self.ranges.insert(index, merged);
+    }
+
+    /// Does the class contain `c`?
+    pub fn contains(&self, c: char) -> bool {
+        let c = c as u32;
+        if c < 128 {
+            self.ascii & (1 << c) != 0
+        } else {
+            let index = self.ranges.partition_point(|(_, end)| *end < c);
+            self.ranges.get(index).is_some_and(|(start, _)| *start <= c)
+        }
+    }
+}
+
+impl Text {
+    /// Gets the longest slice starting at byte offset `start` that consists only of characters in `class`.
+    /// The slice is empty if the character at `start` is not in `class`.
+    /// Will panic if `start` is out of bounds or not on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{CharClass, Text};
+    ///
+    /// let source = Text::new("let count2 = 10;");
+    /// let ident = source.span_of_class(&CharClass::ascii_identifier(), 4);
+    /// assert_eq!("count2", ident.as_str());
+    /// ```
+    pub fn span_of_class(&self, class: &CharClass, start: usize) -> Text {
+        let rest = self.slice(start, self.len());
+        let bytes = rest.as_str().as_bytes();
+        let mut end = 0;
+        while end < bytes.len() {
+            let b = bytes[end];
+            if b < 128 {
+                if class.ascii & (1 << b) == 0 {
+                    break;
+                }
+                end += 1;
+            } else {
+                match rest.as_str()[end..].chars().next() {
+                    Some(c) if class.contains(c) => end += c.len_utf8(),
+                    _ => break,
+                }
+            }
+        }
+        rest.substring(0, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CharClass, Text};
+
+    #[test]
+    pub fn test_char_class() {
+        let mut class = CharClass::new();
+        class.add_range('ä', 'ö');
+        class.add_range('x', 'ā');
+        class.add_char('ø');
+        class.add_range('z', 'a');
+        for c in "xyzäåöøāÿ".chars() {
+            assert!(class.contains(c), "{}", c);
+        }
+        for c in "abwĂ€".chars() {
+            assert!(!class.contains(c), "{}", c);
+        }
+        let mut expected = CharClass::new();
+        expected.add_range('x', 'ā');
+        assert_eq!(expected, class);
+    }
+
+    #[test]
+    pub fn test_span_of_class() {
+        let mut class = CharClass::ascii_alphanumeric();
+        class.add_range('à', 'ÿ');
+        let t = Text::new("  héllo wörld");
+        assert_eq!("", t.span_of_class(&class, 0).as_str());
+        assert_eq!("héllo", t.span_of_class(&class, 2).as_str());
+        assert_eq!("wörld", t.span_of_class(&class, 9).as_str());
+        assert_eq!("", t.span_of_class(&class, t.len()).as_str());
+        assert_eq!(
+            "  ",
+            t.span_of_class(&CharClass::ascii_whitespace(), 0).as_str()
+        );
+    }
+}
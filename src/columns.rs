@@ -0,0 +1,93 @@
+use crate::Text;
+use std::ops::Range;
+
+/// Whether column positions count bytes or `char`s, see [`Text::columns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnUnit {
+    /// Positions are byte offsets. Positions inside a multi-byte `char` are moved back to its start.
+    Bytes,
+    /// Positions count `char`s.
+    Chars,
+}
+
+/// Gets the largest `char` boundary of `s` that is not greater than `index`, clamping it to `s.len()`.
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+impl Text {
+    /// Extracts a single column of a fixed-width record as a slice of `self`, see [`Text::columns`].
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{ColumnUnit, Text};
+    ///
+    /// let record = Text::new("00042Müller    ");
+    /// assert_eq!("Müller    ", record.column(5..15, ColumnUnit::Chars).as_str());
+    /// ```
+    pub fn column(&self, range: Range<usize>, unit: ColumnUnit) -> Text {
+        self.columns(&[range], unit).pop().unwrap_or_default()
+    }
+
+    /// Extracts the columns of a fixed-width record (a single line of a legacy data file) as slices of `self`.
+    /// Ranges are clamped to the record, so columns beyond the end of a short record are empty, and inverted ranges
+    /// are empty as well. Padding is kept; use e.g. [`Text::ops`] to trim it.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{ColumnUnit, Text};
+    ///
+    /// let record = Text::new("0042ACME      NY");
+    /// let columns = record.columns(&[0..4, 4..14, 14..16, 16..20], ColumnUnit::Bytes);
+    /// let columns: Vec<&str> = columns.iter().map(Text::as_str).collect();
+    /// assert_eq!(vec!["0042", "ACME      ", "NY", ""], columns);
+    /// ```
+    pub fn columns(&self, spec: &[Range<usize>], unit: ColumnUnit) -> Vec<Text> {
+        let s = self.as_str();
+        let char_offsets: Option<Vec<usize>> = match unit {
+            ColumnUnit::Bytes => None,
+            ColumnUnit::Chars => Some(
+                s.char_indices()
+                    .map(|(i, _)| i)
+                    .chain(std::iter::once(s.len()))
+                    .collect(),
+            ),
+        };
+        let resolve = |position: usize| match &char_offsets {
+            Some(offsets) => offsets[position.min(offsets.len() - 1)],
+            None => floor_char_boundary(s, position),
+        };
+        spec.iter()
+            .map(|range| {
+                let start = resolve(range.start);
+                let end = resolve(range.end).max(start);
+                self.slice(start, end)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColumnUnit, Text};
+    use std::ops::Range;
+
+    #[test]
+    pub fn test_columns() {
+        let t = Text::new("xaäbx").slice(1, 5);
+        let as_strs = |unit| {
+            t.columns(&[0..2, 2..3, Range { start: 3, end: 1 }, 3..9, 9..10], unit)
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(vec!["a", "ä", "", "b", ""], as_strs(ColumnUnit::Bytes));
+        assert_eq!(vec!["aä", "b", "", "", ""], as_strs(ColumnUnit::Chars));
+        assert_eq!("", Text::default().column(0..3, ColumnUnit::Chars).as_str());
+        assert_eq!("ä", t.column(1..2, ColumnUnit::Chars).as_str());
+    }
+}
@@ -0,0 +1,188 @@
+use crate::Text;
+
+/// Describes the comment syntax of a language for [`Text::strip_comments`] and [`Text::comments`].
+/// String literals are not recognized, so comment markers inside them are treated as comments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentStyle<'a> {
+    /// The marker starting a comment that extends to the end of the line.
+    pub line: Option<&'a str>,
+    /// The markers opening and closing a block comment. Block comments do not nest.
+    pub block: Option<(&'a str, &'a str)>,
+}
+
+impl CommentStyle<'static> {
+    /// `// line` and `/* block */` comments, as in C, Rust or Java.
+    pub const C: CommentStyle<'static> = CommentStyle {
+        line: Some("//"),
+        block: Some(("/*", "*/")),
+    };
+
+    /// `# line` comments, as in shell scripts or Python.
+    pub const HASH: CommentStyle<'static> = CommentStyle {
+        line: Some("#"),
+        block: None,
+    };
+
+    /// `-- line` and `/* block */` comments, as in SQL.
+    pub const SQL: CommentStyle<'static> = CommentStyle {
+        line: Some("--"),
+        block: Some(("/*", "*/")),
+    };
+}
+
+struct CommentSpans<'a, 's> {
+    s: &'s str,
+    style: CommentStyle<'a>,
+    pos: usize,
+    /// The next position of the line and block markers found so far (`Some(None)` if there is none),
+    /// so that a marker is only searched for again once `pos` has passed it.
+    next_line: Option<Option<usize>>,
+    next_block: Option<Option<usize>>,
+}
+
+impl<'a, 's> CommentSpans<'a, 's> {
+    fn new(s: &'s str, style: CommentStyle<'a>) -> Self {
+        Self {
+            s,
+            style,
+            pos: 0,
+            next_line: None,
+            next_block: None,
+        }
+    }
+
+    fn find_cached(
+        s: &str,
+        pos: usize,
+        marker: Option<&str>,
+        cached: &mut Option<Option<usize>>,
+    ) -> Option<usize> {
+        match *cached {
+            Some(Some(found)) if found >= pos => return Some(found),
+            Some(None) => return None,
+            _ => {}
+        }
+        let found = marker
+            .filter(|m| !m.is_empty())
+            .and_then(|m| s[pos..].find(m))
+            .map(|i| pos + i);
+        *cached = Some(found);
+        found
+    }
+
+    fn line_end(rest: &str, start: usize) -> (usize, usize) {
+        let end = rest[start..].find('\n').map_or(rest.len(), |e| start + e);
+        (start, end)
+    }
+}
+
+impl Iterator for CommentSpans<'_, '_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.s[self.pos..];
+        let line = Self::find_cached(self.s, self.pos, self.style.line, &mut self.next_line)
+            .map(|found| found - self.pos);
+        let block = self.style.block.and_then(|(open, close)| {
+            Self::find_cached(self.s, self.pos, Some(open), &mut self.next_block)
+                .map(|found| (found - self.pos, open, close))
+        });
+        let (start, end) = match (line, block) {
+            (Some(l), Some((b, _, _))) if l < b => Self::line_end(rest, l),
+            (Some(l), None) => Self::line_end(rest, l),
+            (_, Some((b, open, close))) => {
+                let body = b + open.len();
+                let end = match rest[body..].find(close) {
+                    Some(e) if !close.is_empty() => body + e + close.len(),
+                    _ => rest.len(),
+                };
+                (b, end)
+            }
+            (None, None) => return None,
+        };
+        let span = (self.pos + start, self.pos + end);
+        self.pos = span.1;
+        Some(span)
+    }
+}
+
+impl Text {
+    /// Iterates over all comments in the [`Text`], including their markers, as slices of `self`.
+    /// Line comments do not include the terminating newline.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{CommentStyle, Text};
+    ///
+    /// let code = Text::new("let a = 1; // one\n/* two */ let b = 2;");
+    /// let comments: Vec<Text> = code.comments(CommentStyle::C).collect();
+    /// assert_eq!("// one", comments[0].as_str());
+    /// assert_eq!("/* two */", comments[1].as_str());
+    /// ```
+    pub fn comments<'a>(&'a self, style: CommentStyle<'a>) -> impl Iterator<Item = Text> + 'a {
+        CommentSpans::new(self.as_str(), style).map(move |(start, end)| self.slice(start, end))
+    }
+
+    /// Removes all comments from the [`Text`]. Line comments are removed up to, but not including, the newline.
+    /// If there are no comments, `self` is returned without copying.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{CommentStyle, Text};
+    ///
+    /// let script = Text::new("echo hi # greet\nexit");
+    /// assert_eq!("echo hi \nexit", script.strip_comments(CommentStyle::HASH).as_str());
+    /// ```
+    pub fn strip_comments(&self, style: CommentStyle<'_>) -> Text {
+        let s = self.as_str();
+        let mut spans = CommentSpans::new(s, style).peekable();
+        if spans.peek().is_none() {
+            return self.clone();
+        }
+        let mut stripped = String::with_capacity(s.len());
+        let mut last = 0;
+        for (start, end) in spans {
+            stripped.push_str(&s[last..start]);
+            last = end;
+        }
+        stripped.push_str(&s[last..]);
+        Text::new(stripped.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CommentStyle, Text};
+
+    #[test]
+    pub fn test_comments() {
+        let code = Text::new("a -- x\nb /* -- y */ c /* unterminated");
+        let comments: Vec<Text> = code.comments(CommentStyle::SQL).collect();
+        assert_eq!(3, comments.len());
+        assert_eq!("-- x", comments[0].as_str());
+        assert_eq!("/* -- y */", comments[1].as_str());
+        assert_eq!("/* unterminated", comments[2].as_str());
+        assert_eq!("a \nb  c ", code.strip_comments(CommentStyle::SQL).as_str());
+    }
+
+    #[test]
+    pub fn test_markers_inside_comments() {
+        let code = Text::new("// a\n// b /* c */\nx /* d */ // e\n/* // f */");
+        let comments: Vec<String> = code
+            .comments(CommentStyle::C)
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(
+            vec!["// a", "// b /* c */", "/* d */", "// e", "/* // f */"],
+            comments
+        );
+    }
+
+    #[test]
+    pub fn test_strip_no_comments() {
+        let code = Text::new("x = 1").slice(0, 3);
+        let stripped = code.strip_comments(CommentStyle::C);
+        assert_eq!(code.as_str().as_ptr(), stripped.as_str().as_ptr());
+        assert_eq!("x =", stripped.as_str());
+    }
+}
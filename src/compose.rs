@@ -0,0 +1,105 @@
+use crate::Text;
+
+/// The indentation added per level by [`Composer::indented`].
+const INDENT: &str = "    ";
+
+/// Writes structured text such as generated code into a single buffer, see [`Text::compose`].
+/// All methods return the composer again, so that calls can be chained.
+#[derive(Debug, Default)]
+pub struct Composer {
+    buffer: String,
+    depth: usize,
+    at_line_start: bool,
+}
+
+impl Composer {
+    /// Writes `s`, indenting every line that starts within it according to the current [`Composer::indented`] depth.
+    /// Empty lines are not indented.
+    pub fn literal(&mut self, s: &str) -> &mut Self {
+        for piece in s.split_inclusive('\n') {
+            if self.at_line_start && piece != "\n" {
+                for _ in 0..self.depth {
+                    self.buffer.push_str(INDENT);
+                }
+            }
+            self.buffer.push_str(piece);
+            self.at_line_start = piece.ends_with('\n');
+        }
+        self
+    }
+
+    /// Writes a [`Text`], like [`Composer::literal`].
+    pub fn text(&mut self, text: &Text) -> &mut Self {
+        self.literal(text.as_str())
+    }
+
+    /// Writes `s` `n` times, like [`Composer::literal`].
+    pub fn repeat(&mut self, s: &str, n: usize) -> &mut Self {
+        for _ in 0..n {
+            self.literal(s);
+        }
+        self
+    }
+
+    /// Ends the current line.
+    pub fn newline(&mut self) -> &mut Self {
+        self.literal("\n")
+    }
+
+    /// Writes a section in which every line is indented by four more spaces.
+    pub fn indented<F: FnOnce(&mut Composer)>(&mut self, f: F) -> &mut Self {
+        self.depth += 1;
+        f(self);
+        self.depth -= 1;
+        self
+    }
+}
+
+impl Text {
+    /// Creates a [`Text`] by passing a [`Composer`] to `f`, which writes literals, texts, repetitions and indented sections.
+    /// Everything is written into one buffer, which becomes the [`Text`] without being copied.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let fields = [Text::new("x"), Text::new("y")];
+    /// let code = Text::compose(|c| {
+    ///     c.literal("struct Point {\n").indented(|c| {
+    ///         for field in &fields {
+    ///             c.text(field).literal(": f64,\n");
+    ///         }
+    ///     });
+    ///     c.literal("}\n");
+    /// });
+    /// assert_eq!("struct Point {\n    x: f64,\n    y: f64,\n}\n", code.as_str());
+    /// ```
+    pub fn compose<F: FnOnce(&mut Composer)>(f: F) -> Text {
+        let mut composer = Composer {
+            at_line_start: true,
+            ..Composer::default()
+        };
+        f(&mut composer);
+        Text::from_owner(composer.buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_compose() {
+        let text = Text::compose(|c| {
+            c.literal("a").indented(|c| {
+                c.literal("b\n\nc\n").indented(|c| {
+                    c.repeat("-", 3).newline();
+                });
+                c.literal("d");
+            });
+            c.newline().text(&Text::new("e"));
+        });
+        assert_eq!("ab\n\n    c\n        ---\n    d\ne", text.as_str());
+        assert_eq!("", Text::compose(|_| ()).as_str());
+    }
+}
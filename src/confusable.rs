@@ -0,0 +1,59 @@
+use crate::Text;
+use unicode_security::skeleton;
+
+impl Text {
+    /// Computes the confusable skeleton of the [`Text`] as defined by UTS #39: two strings are visually confusable
+    /// if their skeletons are equal. Skeletons are only meant for comparison, not for display.
+    /// If the [`Text`] is its own skeleton, it is returned without copying.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!("paypal", Text::new("pаypаl").confusable_skeleton().as_str());
+    /// ```
+    pub fn confusable_skeleton(&self) -> Text {
+        let s = self.as_str();
+        if skeleton(s).eq(s.chars()) {
+            self.clone()
+        } else {
+            let skeleton: String = skeleton(s).collect();
+            Text::new(skeleton.as_str())
+        }
+    }
+
+    /// Is the [`Text`] visually confusable with `other`, i.e. do both have the same skeleton
+    /// (see [`Text::confusable_skeleton`])? The skeletons are compared without being allocated.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let registered = Text::new("admin");
+    /// assert!(registered.is_confusable_with(&Text::new("аdmin")));
+    /// assert!(!registered.is_confusable_with(&Text::new("admins")));
+    /// ```
+    pub fn is_confusable_with(&self, other: &Text) -> bool {
+        skeleton(self.as_str()).eq(skeleton(other.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_confusables() {
+        let plain = Text::new("scope");
+        assert_eq!(
+            plain.as_str().as_ptr(),
+            plain.confusable_skeleton().as_str().as_ptr()
+        );
+        let spoofed = Text::new("ѕсоре");
+        assert!(spoofed.is_confusable_with(&plain));
+        assert_eq!(plain, spoofed.confusable_skeleton());
+        assert!(Text::new("rn").is_confusable_with(&Text::new("m")));
+        assert!(Text::new("e\u{301}").is_confusable_with(&Text::new("é")));
+        assert!(!Text::new("scope").is_confusable_with(&Text::new("slope")));
+    }
+}
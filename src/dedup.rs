@@ -0,0 +1,87 @@
+use crate::Text;
+use std::collections::HashSet;
+
+/// An iterator adapter yielding only the first occurrence of every distinct [`Text`], in order.
+/// Texts that are the very same slice of a buffer as an earlier first occurrence are recognized by their address
+/// and length, without hashing their contents, which speeds up deduplicating tokens sliced from a shared source.
+///
+/// # Example
+/// ```
+/// use quetta::{Text, TextDedup};
+///
+/// let source = Text::new("a b a c b");
+/// let words = source.split_terminator_char(' ').chain(std::iter::once(source.slice(0, 1)));
+/// let unique: Vec<String> = TextDedup::new(words).map(|t| t.to_string()).collect();
+/// assert_eq!(vec!["a", "b", "c"], unique);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextDedup<I> {
+    iter: I,
+    seen: HashSet<Text>,
+    /// Address and length of every first occurrence, which `seen` keeps alive.
+    spans: HashSet<(usize, usize)>,
+}
+
+impl<I: Iterator<Item = Text>> TextDedup<I> {
+    /// Creates a [`TextDedup`] over `iter`.
+    pub fn new<T: IntoIterator<IntoIter = I>>(iter: T) -> Self {
+        Self {
+            iter: iter.into_iter(),
+            seen: HashSet::new(),
+            spans: HashSet::new(),
+        }
+    }
+
+    /// Gets the number of distinct texts yielded so far.
+    pub fn distinct_len(&self) -> usize {
+        self.seen.len()
+    }
+}
+
+impl<I: Iterator<Item = Text>> Iterator for TextDedup<I> {
+    type Item = Text;
+
+    fn next(&mut self) -> Option<Text> {
+        for text in self.iter.by_ref() {
+            let span = (text.as_str().as_ptr() as usize, text.len());
+            if self.spans.contains(&span) || self.seen.contains(&text) {
+                continue;
+            }
+            self.spans.insert(span);
+            self.seen.insert(text.clone());
+            return Some(text);
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Text, TextDedup};
+
+    #[test]
+    pub fn test_text_dedup() {
+        let shared = Text::new("xyx");
+        let texts = vec![
+            shared.slice(0, 1),
+            Text::new("x"),
+            shared.slice(2, 3),
+            shared.slice(0, 2),
+            shared.slice(0, 1),
+            Text::default(),
+            shared.slice(1, 1),
+        ];
+        let mut dedup = TextDedup::new(texts);
+        let unique: Vec<Text> = dedup.by_ref().collect();
+        assert_eq!(
+            vec![Text::new("x"), Text::new("xy"), Text::default()],
+            unique
+        );
+        assert_eq!(3, dedup.distinct_len());
+        assert_eq!(0, TextDedup::new(Vec::new()).count());
+    }
+}
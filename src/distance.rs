@@ -0,0 +1,161 @@
+use crate::Text;
+
+impl Text {
+    /// Computes the Levenshtein distance between the [`Text`] and `other` in `char`s, or `None` if it exceeds `k`.
+    /// Only a band of width `2k + 1` of the usual dynamic programming table is computed, and the computation stops
+    /// as soon as the distance is known to exceed `k`, which makes this O(k·n) instead of O(n²).
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let word = Text::new("kitten");
+    /// assert_eq!(Some(3), word.levenshtein_within("sitting", 3));
+    /// assert_eq!(None, word.levenshtein_within("sitting", 2));
+    /// ```
+    pub fn levenshtein_within(&self, other: &str, k: usize) -> Option<usize> {
+        let a: Vec<char> = self.as_str().chars().collect();
+        let b: Vec<char> = other.chars().collect();
+        if a.len().abs_diff(b.len()) > k {
+            return None;
+        }
+        // The distance never exceeds the longer length, so larger bounds (like `usize::MAX`) change nothing
+        let k = k.min(a.len().max(b.len()));
+        // Cells outside of the band are treated as exceeding `k`
+        let beyond = k + 1;
+        let mut previous: Vec<usize> = (0..=b.len()).map(|j| j.min(beyond)).collect();
+        let mut current = vec![beyond; b.len() + 1];
+        for (i, &ca) in a.iter().enumerate() {
+            let row = i + 1;
+            let low = row.saturating_sub(k);
+            let high = row.saturating_add(k).min(b.len());
+            // Only the cells just outside of the band are read by this and the next row, so only those need resetting
+            let mut row_min = beyond;
+            if low == 0 {
+                current[0] = row.min(beyond);
+                row_min = current[0];
+            } else {
+                current[low - 1] = beyond;
+            }
+            if let Some(cell) = current.get_mut(high + 1) {
+                *cell = beyond;
+            }
+            for j in low.max(1)..=high {
+                let cost = usize::from(ca != b[j - 1]);
+                let distance = (previous[j - 1] + cost)
+                    .min(previous[j] + 1)
+                    .min(current[j - 1] + 1)
+                    .min(beyond);
+                current[j] = distance;
+                row_min = row_min.min(distance);
+            }
+            if row_min > k {
+                return None;
+            }
+            std::mem::swap(&mut previous, &mut current);
+        }
+        Some(previous[b.len()]).filter(|&distance| distance <= k)
+    }
+
+    /// Finds the candidate closest to the [`Text`] by Levenshtein distance, if any is within `k` edits,
+    /// e.g. to suggest a fix for a mistyped identifier. Ties go to the earlier candidate.
+    /// The bound tightens with every better candidate found, so later candidates are rejected quickly.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let commands = [Text::new("commit"), Text::new("checkout"), Text::new("cherry-pick")];
+    /// assert_eq!(Some(Text::new("commit")), Text::new("comit").closest_match(&commands, 2));
+    /// assert_eq!(None, Text::new("push").closest_match(&commands, 2));
+    /// ```
+    pub fn closest_match<'a, I: IntoIterator<Item = &'a Text>>(
+        &self,
+        candidates: I,
+        k: usize,
+    ) -> Option<Text> {
+        let mut best: Option<(usize, &Text)> = None;
+        for candidate in candidates {
+            let bound = match best {
+                Some((0, _)) => break,
+                Some((distance, _)) => distance - 1,
+                None => k,
+            };
+            if let Some(distance) = self.levenshtein_within(candidate.as_str(), bound) {
+                best = Some((distance, candidate));
+            }
+        }
+        best.map(|(_, candidate)| candidate.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    fn levenshtein(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+        for (i, ca) in a.chars().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+            for j in 1..=b.len() {
+                let above = row[j];
+                row[j] = (diagonal + usize::from(ca != b[j - 1]))
+                    .min(row[j] + 1)
+                    .min(row[j - 1] + 1);
+                diagonal = above;
+            }
+        }
+        row[b.len()]
+    }
+
+    #[test]
+    pub fn test_levenshtein_within() {
+        let words = [
+            "",
+            "a",
+            "ab",
+            "ba",
+            "abc",
+            "äbc",
+            "flaw",
+            "lawn",
+            "saturday",
+            "sunday",
+            "abcdefghij",
+            "bcdefghijk",
+            "abcdxfghj",
+        ];
+        for a in words {
+            for b in words {
+                let distance = levenshtein(a, b);
+                let t = Text::new(a);
+                for k in 0..6 {
+                    let expected = if distance <= k { Some(distance) } else { None };
+                    assert_eq!(expected, t.levenshtein_within(b, k), "{} {} {}", a, b, k);
+                }
+                assert_eq!(Some(distance), t.levenshtein_within(b, usize::MAX));
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_closest_match() {
+        let candidates = [Text::new("abd"), Text::new("abc"), Text::new("abx")];
+        assert_eq!(
+            Some(Text::new("abc")),
+            Text::new("abc").closest_match(&candidates, 1)
+        );
+        assert_eq!(
+            Some(Text::new("abd")),
+            Text::new("ab").closest_match(&candidates, 1)
+        );
+        assert_eq!(None, Text::new("x").closest_match(&candidates, 1));
+        assert_eq!(None, Text::new("x").closest_match(&[], 5));
+        assert_eq!(
+            Some(Text::new("abd")),
+            Text::new("abc").closest_match(&[Text::new("abd")], usize::MAX)
+        );
+    }
+}
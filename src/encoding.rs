@@ -0,0 +1,137 @@
+use crate::Text;
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+/// The error returned when an encoding label is not known to `encoding_rs`,
+/// or when encoding to an encoding that can only be decoded (like `"replacement"`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownEncodingError {
+    label: String,
+    decode_only: bool,
+}
+
+impl UnknownEncodingError {
+    /// Gets the label that was not recognized.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Is the encoding known, but can only be decoded?
+    pub fn is_decode_only(&self) -> bool {
+        self.decode_only
+    }
+}
+
+impl Display for UnknownEncodingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.decode_only {
+            write!(f, "Encoding can only be decoded: {}", self.label)
+        } else {
+            write!(f, "Unknown encoding: {}", self.label)
+        }
+    }
+}
+
+impl std::error::Error for UnknownEncodingError {}
+
+fn lookup(label: &str) -> Result<&'static Encoding, UnknownEncodingError> {
+    Encoding::for_label(label.as_bytes()).ok_or_else(|| UnknownEncodingError {
+        label: label.to_string(),
+        decode_only: false,
+    })
+}
+
+impl Text {
+    /// Decodes `bytes` in the encoding named by `label` (a WHATWG encoding label such as `"windows-1252"` or `"shift_jis"`).
+    /// A byte order mark, if present, overrides the given encoding. Malformed sequences are replaced with `U+FFFD`.
+    /// Requires the `encoding_rs` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::from_bytes_with_encoding(b"caf\xe9", "windows-1252").unwrap();
+    /// assert_eq!("café", text.as_str());
+    /// assert!(Text::from_bytes_with_encoding(b"", "klingon").is_err());
+    /// ```
+    pub fn from_bytes_with_encoding(
+        bytes: &[u8],
+        label: &str,
+    ) -> Result<Text, UnknownEncodingError> {
+        let (decoded, _, _) = lookup(label)?.decode(bytes);
+        Ok(Text::new(&*decoded))
+    }
+
+    /// Encodes the [`Text`] in the encoding named by `label`.
+    /// Characters that cannot be represented are written as HTML numeric character references.
+    /// No copy is made if the [`Text`] is already valid in the target encoding (e.g. UTF-8, or ASCII for most encodings).
+    /// UTF-16 is written without a byte order mark. Returns an error for unknown labels
+    /// and for encodings that can only be decoded, like `"replacement"`.
+    /// Requires the `encoding_rs` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("café");
+    /// assert_eq!(&b"caf\xe9"[..], &*text.encode_to("latin1").unwrap());
+    /// ```
+    pub fn encode_to(&self, label: &str) -> Result<Cow<'_, [u8]>, UnknownEncodingError> {
+        let encoding = lookup(label)?;
+        if encoding == UTF_16LE || encoding == UTF_16BE {
+            let units = self.as_str().encode_utf16();
+            let encoded = if encoding == UTF_16LE {
+                units.flat_map(u16::to_le_bytes).collect()
+            } else {
+                units.flat_map(u16::to_be_bytes).collect()
+            };
+            return Ok(Cow::Owned(encoded));
+        }
+        let (encoded, used, _) = encoding.encode(self.as_str());
+        if used != encoding {
+            return Err(UnknownEncodingError {
+                label: label.to_string(),
+                decode_only: true,
+            });
+        }
+        Ok(encoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+    use std::borrow::Cow;
+
+    #[test]
+    pub fn test_round_trip() {
+        let bytes = b"\x93quoted\x94 \x80";
+        let text = Text::from_bytes_with_encoding(bytes, "windows-1252").unwrap();
+        assert_eq!("\u{201c}quoted\u{201d} €", text.as_str());
+        assert_eq!(&bytes[..], &*text.encode_to("cp1252").unwrap());
+        let ascii = Text::new("plain");
+        assert!(matches!(
+            ascii.encode_to("windows-1252").unwrap(),
+            Cow::Borrowed(_)
+        ));
+        let err = Text::from_bytes_with_encoding(bytes, "nope").unwrap_err();
+        assert_eq!("nope", err.label());
+        assert!(!err.is_decode_only());
+    }
+
+    #[test]
+    pub fn test_encode_utf16() {
+        let text = Text::new("hi€");
+        let le = text.encode_to("utf-16le").unwrap();
+        assert_eq!(&[b'h', 0, b'i', 0, 0xac, 0x20][..], &*le);
+        let be = text.encode_to("utf-16be").unwrap();
+        assert_eq!(&[0, b'h', 0, b'i', 0x20, 0xac][..], &*be);
+        assert_eq!(
+            text,
+            Text::from_bytes_with_encoding(&le, "utf-16le").unwrap()
+        );
+        let err = text.encode_to("replacement").unwrap_err();
+        assert!(err.is_decode_only());
+    }
+}
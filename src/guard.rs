@@ -0,0 +1,99 @@
+use crate::Text;
+use std::ops::Range;
+
+/// A [`Text`] that is known to be a slice of a specific root document.
+/// Guards can only be created from the document itself or lifted from another guard of the same document,
+/// so a span from one file cannot accidentally be applied to another.
+///
+/// # Example
+/// ```
+/// use quetta::{Text, TextGuard};
+///
+/// let file = TextGuard::new(Text::new("fn main() {}"));
+/// let name = file.lift(&file.text().slice(3, 7)).unwrap();
+/// assert_eq!(3..7, name.range());
+///
+/// let other = Text::new("fn main() {}");
+/// assert!(file.lift(&other.slice(3, 7)).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextGuard {
+    document: Text,
+    text: Text,
+}
+
+impl TextGuard {
+    /// Creates a guard for a whole root document.
+    pub fn new(document: Text) -> Self {
+        Self {
+            text: document.clone(),
+            document,
+        }
+    }
+
+    /// Guards `slice` if it is a slice of the same document, see [`Text::contains_slice`].
+    /// Returns `None` if it belongs to another buffer or lies outside of the document.
+    pub fn lift(&self, slice: &Text) -> Option<TextGuard> {
+        if self.document.contains_slice(slice) {
+            Some(Self {
+                document: self.document.clone(),
+                text: slice.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Slices the guarded [`Text`], see [`Text::slice`]. The result stays guarded.
+    /// Will panic if the slice exceeds the [`Text`]'s bounds or does not start and end on a `char` boundary.
+    pub fn slice(&self, start: usize, end: usize) -> TextGuard {
+        Self {
+            document: self.document.clone(),
+            text: self.text.slice(start, end),
+        }
+    }
+
+    /// Gets the guarded [`Text`].
+    pub fn text(&self) -> &Text {
+        &self.text
+    }
+
+    /// Gets the root document.
+    pub fn document(&self) -> &Text {
+        &self.document
+    }
+
+    /// Gets the byte range of the guarded [`Text`] within the document.
+    pub fn range(&self) -> Range<usize> {
+        let start = self.text.offset_in_parent() - self.document.offset_in_parent();
+        start..start + self.text.len()
+    }
+
+    /// Do both guards belong to the same document?
+    pub fn same_document(&self, other: &TextGuard) -> bool {
+        self.document.contains_slice(&other.document)
+            && other.document.contains_slice(&self.document)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Text, TextGuard};
+
+    #[test]
+    pub fn test_guard() {
+        let source = Text::new("xlet a = 1;");
+        let doc = TextGuard::new(source.slice(1, 11));
+        let outside = source.slice(0, 4);
+        assert!(doc.lift(&outside).is_none());
+        let stmt = doc.lift(&source.slice(5, 10)).unwrap();
+        assert_eq!(4..9, stmt.range());
+        let value = stmt.slice(4, 5);
+        assert_eq!("1", value.text().as_str());
+        assert_eq!(8..9, value.range());
+        assert!(value.same_document(&doc));
+        assert!(!value.same_document(&TextGuard::new(source.clone())));
+        let copy = Text::new(doc.text().as_str());
+        assert!(doc.lift(&copy).is_none());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::Text;
+use std::cell::Cell;
+use std::ops::Deref;
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A position in the [`Text`]: the index of a `char` or grapheme and its byte offset.
+#[derive(Debug, Clone, Copy, Default)]
+struct Hint {
+    index: usize,
+    offset: usize,
+}
+
+/// A [`Text`] that remembers the position of the last positional access, so that sequential access patterns
+/// (like moving a cursor through a line) only scan from there instead of from the start every time.
+/// Random access is still O(n) in the worst case. Dereferences to the underlying [`Text`].
+///
+/// # Example
+/// ```
+/// use quetta::Text;
+///
+/// let line = Text::new("Grüße").hinted();
+/// let chars: String = (0..5).filter_map(|n| line.chars_nth(n)).collect();
+/// assert_eq!("Grüße", chars);
+/// assert_eq!(Some('r'), line.chars_nth(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HintedText {
+    text: Text,
+    char_hint: Cell<Hint>,
+    #[cfg(feature = "unicode-segmentation")]
+    grapheme_hint: Cell<Hint>,
+}
+
+impl HintedText {
+    /// Wraps `text`, starting without hints.
+    pub fn new(text: Text) -> Self {
+        Self {
+            text,
+            char_hint: Cell::default(),
+            #[cfg(feature = "unicode-segmentation")]
+            grapheme_hint: Cell::default(),
+        }
+    }
+
+    /// Gets the underlying [`Text`].
+    pub fn text(&self) -> &Text {
+        &self.text
+    }
+
+    /// Unwraps the underlying [`Text`].
+    pub fn into_inner(self) -> Text {
+        self.text
+    }
+
+    /// Gets the `n`th `char`, scanning from the position of the last access (or the start, if that is closer).
+    pub fn chars_nth(&self, n: usize) -> Option<char> {
+        let s = self.text.as_str();
+        let hint = self.char_hint.get();
+        let offset = if n >= hint.index {
+            s[hint.offset..]
+                .char_indices()
+                .nth(n - hint.index)
+                .map(|(i, _)| hint.offset + i)
+        } else if n > hint.index - n {
+            s[..hint.offset]
+                .char_indices()
+                .nth_back(hint.index - n - 1)
+                .map(|(i, _)| i)
+        } else {
+            s.char_indices().nth(n).map(|(i, _)| i)
+        }?;
+        self.char_hint.set(Hint { index: n, offset });
+        s[offset..].chars().next()
+    }
+
+    /// Gets the `n`th extended grapheme cluster as a slice,
+    /// scanning from the position of the last access (or the start, if that is closer).
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let word = Text::new("ne\u{301}e").hinted();
+    /// assert_eq!("e\u{301}", word.graphemes_nth(1).unwrap().as_str());
+    /// assert_eq!("e", word.graphemes_nth(2).unwrap().as_str());
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn graphemes_nth(&self, n: usize) -> Option<Text> {
+        let s = self.text.as_str();
+        let hint = self.grapheme_hint.get();
+        let (offset, grapheme) = if n >= hint.index {
+            s[hint.offset..]
+                .grapheme_indices(true)
+                .nth(n - hint.index)
+                .map(|(i, g)| (hint.offset + i, g))
+        } else if n > hint.index - n {
+            s[..hint.offset]
+                .grapheme_indices(true)
+                .nth_back(hint.index - n - 1)
+        } else {
+            s.grapheme_indices(true).nth(n)
+        }?;
+        self.grapheme_hint.set(Hint { index: n, offset });
+        Some(self.text.substring(offset, grapheme.len()))
+    }
+}
+
+impl Deref for HintedText {
+    type Target = Text;
+
+    fn deref(&self) -> &Text {
+        &self.text
+    }
+}
+
+impl Text {
+    /// Creates a [`HintedText`] for efficient sequential positional access.
+    pub fn hinted(&self) -> HintedText {
+        HintedText::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_chars_nth() {
+        let t = Text::new("aébçdè");
+        let hinted = t.hinted();
+        let order = [0, 1, 2, 5, 4, 3, 6, 1, 5, 0, 2, 100, 3];
+        for n in order.iter() {
+            assert_eq!(t.as_str().chars().nth(*n), hinted.chars_nth(*n), "{}", n);
+        }
+        assert_eq!(None, Text::default().hinted().chars_nth(0));
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    pub fn test_graphemes_nth() {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let t = Text::new("a\u{308}🇩🇪b\r\nç🇫🇷x");
+        let expected: Vec<&str> = t.as_str().graphemes(true).collect();
+        let hinted = t.hinted();
+        let order = [0, 1, 2, 6, 5, 3, 4, 1, 7, 0, 6, 2];
+        for n in order.iter() {
+            assert_eq!(
+                expected.get(*n).copied(),
+                hinted.graphemes_nth(*n).as_ref().map(Text::as_str),
+                "{}",
+                n
+            );
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::Text;
+
+fn escape_char(c: char) -> Option<&'static str> {
+    match c {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        '"' => Some("&quot;"),
+        '\'' => Some("&#39;"),
+        _ => None,
+    }
+}
+
+/// The longest entity name that is looked for, to avoid scanning far ahead for a `;`.
+const MAX_ENTITY_LEN: usize = 32;
+
+/// Decodes the entity at the start of `s` (which starts with `&`), returning the character and the entity's length.
+fn decode_entity(s: &str) -> Option<(char, usize)> {
+    let end = s.bytes().take(MAX_ENTITY_LEN).position(|b| b == b';')?;
+    let name = &s[1..end];
+    let c = match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number
+                .strip_prefix('x')
+                .or_else(|| number.strip_prefix('X'))
+            {
+                Some(hex) if !hex.is_empty() && hex.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                    u32::from_str_radix(hex, 16).ok()
+                }
+                None if !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) => {
+                    number.parse().ok()
+                }
+                _ => return None,
+            };
+            // Like browsers, replace NUL, surrogates and codes beyond Unicode instead of keeping the reference.
+            code.filter(|&code| code != 0)
+                .and_then(std::char::from_u32)
+                .unwrap_or(std::char::REPLACEMENT_CHARACTER)
+        }
+    };
+    Some((c, end + 1))
+}
+
+impl Text {
+    /// Escapes the characters `&`, `<`, `>`, `"` and `'` for use in HTML text and attribute values.
+    /// If none of them occur, `self` is returned without copying.
+    /// Requires the `html` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("<a href=\"x\">");
+    /// assert_eq!("&lt;a href=&quot;x&quot;&gt;", text.escape_html().as_str());
+    /// ```
+    pub fn escape_html(&self) -> Text {
+        let s = self.as_str();
+        let first = match s.find(|c| escape_char(c).is_some()) {
+            Some(first) => first,
+            None => return self.clone(),
+        };
+        let mut escaped = String::with_capacity(s.len() + 16);
+        escaped.push_str(&s[..first]);
+        for c in s[first..].chars() {
+            match escape_char(c) {
+                Some(entity) => escaped.push_str(entity),
+                None => escaped.push(c),
+            }
+        }
+        Text::new(escaped.as_str())
+    }
+
+    /// Decodes HTML character references: numeric ones (`&#233;`, `&#xE9;`) and the named entities
+    /// `&amp;`, `&lt;`, `&gt;`, `&quot;`, `&apos;` and `&nbsp;`. Unknown or malformed references are kept as they are.
+    /// Numeric references to NUL, surrogates or codes beyond Unicode are decoded to `U+FFFD`.
+    /// If there is nothing to decode, `self` is returned without copying.
+    /// Requires the `html` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("Caf&#xE9; &amp; bar &copy;");
+    /// assert_eq!("Café & bar &copy;", text.unescape_html_entities().as_str());
+    /// ```
+    pub fn unescape_html_entities(&self) -> Text {
+        let s = self.as_str();
+        let mut unescaped: Option<String> = None;
+        let mut copied = 0;
+        for (i, _) in s.match_indices('&') {
+            if i < copied {
+                continue;
+            }
+            if let Some((c, len)) = decode_entity(&s[i..]) {
+                let out = unescaped.get_or_insert_with(|| String::with_capacity(s.len()));
+                out.push_str(&s[copied..i]);
+                out.push(c);
+                copied = i + len;
+            }
+        }
+        match unescaped {
+            Some(mut out) => {
+                out.push_str(&s[copied..]);
+                Text::new(out.as_str())
+            }
+            None => self.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_escape_round_trip() {
+        let t = Text::new("if a < b && c > 'd' { \"ok\" }");
+        let escaped = t.escape_html();
+        assert_eq!(
+            "if a &lt; b &amp;&amp; c &gt; &#39;d&#39; { &quot;ok&quot; }",
+            escaped.as_str()
+        );
+        assert_eq!(t, escaped.unescape_html_entities());
+    }
+
+    #[test]
+    pub fn test_no_allocation() {
+        let t = Text::new("plain & simple &unknown; &#xZZ;");
+        let escaped = Text::new("plain");
+        assert_eq!(
+            escaped.as_str().as_ptr(),
+            escaped.escape_html().as_str().as_ptr()
+        );
+        assert_eq!(
+            t.as_str().as_ptr(),
+            t.unescape_html_entities().as_str().as_ptr()
+        );
+    }
+
+    #[test]
+    pub fn test_numeric_entities() {
+        let t = Text::new("&#x+41;&#+65;&#-1;&#x;&#65;&#X42;");
+        assert_eq!(
+            "&#x+41;&#+65;&#-1;&#x;AB",
+            t.unescape_html_entities().as_str()
+        );
+        let t = Text::new("&#0;&#xD800;&#x110000;&#99999999999;");
+        assert_eq!("\u{FFFD}".repeat(4), t.unescape_html_entities().as_str());
+    }
+}
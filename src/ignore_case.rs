@@ -0,0 +1,190 @@
+use crate::Text;
+use std::cmp::Ordering;
+
+/// Lowercases `s` lazily, char by char, without allocating.
+fn folded(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars().flat_map(char::to_lowercase)
+}
+
+impl Text {
+    /// Compares two [`Text`]s for equality, ignoring case. Characters are lowercased on the fly,
+    /// so no lowercase copies are allocated.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert!(Text::new("Straße").eq_ignore_case(&Text::new("STRAßE")));
+    /// assert!(!Text::new("a").eq_ignore_case(&Text::new("b")));
+    /// ```
+    pub fn eq_ignore_case(&self, other: &Text) -> bool {
+        let (a, b) = (self.as_str(), other.as_str());
+        if a.is_ascii() && b.is_ascii() {
+            a.eq_ignore_ascii_case(b)
+        } else {
+            folded(a).eq(folded(b))
+        }
+    }
+
+    /// Compares two [`Text`]s lexicographically, ignoring case. Characters are lowercased on the fly,
+    /// so no lowercase copies are allocated.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Ordering::Less, Text::new("apple").cmp_ignore_case(&Text::new("Banana")));
+    /// assert_eq!(Ordering::Equal, Text::new("ÄPFEL").cmp_ignore_case(&Text::new("äpfel")));
+    /// ```
+    pub fn cmp_ignore_case(&self, other: &Text) -> Ordering {
+        let (a, b) = (self.as_str(), other.as_str());
+        if a.is_ascii() && b.is_ascii() {
+            a.bytes()
+                .map(|c| c.to_ascii_lowercase())
+                .cmp(b.bytes().map(|c| c.to_ascii_lowercase()))
+        } else {
+            folded(a).cmp(folded(b))
+        }
+    }
+
+    /// Does the [`Text`] start with `prefix`, ignoring ASCII case?
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let header = Text::new("Content-Type: text/plain");
+    /// assert!(header.starts_with_ignore_ascii_case("content-type:"));
+    /// ```
+    pub fn starts_with_ignore_ascii_case(&self, prefix: &str) -> bool {
+        let s = self.as_str().as_bytes();
+        s.len() >= prefix.len() && s[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+    }
+
+    /// Does the [`Text`] end with `suffix`, ignoring ASCII case?
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert!(Text::new("photo.JPG").ends_with_ignore_ascii_case(".jpg"));
+    /// ```
+    pub fn ends_with_ignore_ascii_case(&self, suffix: &str) -> bool {
+        let s = self.as_str().as_bytes();
+        s.len() >= suffix.len()
+            && s[s.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+    }
+
+    /// Does the [`Text`] contain `needle`, ignoring ASCII case?
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert!(Text::new("Connection: Keep-Alive").contains_ignore_ascii_case("keep-alive"));
+    /// ```
+    pub fn contains_ignore_ascii_case(&self, needle: &str) -> bool {
+        let needle = needle.as_bytes();
+        needle.is_empty()
+            || self
+                .as_str()
+                .as_bytes()
+                .windows(needle.len())
+                .any(|w| w.eq_ignore_ascii_case(needle))
+    }
+
+    /// Does the [`Text`] start with `prefix`, ignoring case? Unlike [`Text::starts_with_ignore_ascii_case`],
+    /// this handles all of Unicode, lowercasing on the fly.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert!(Text::new("ÜBER alles").starts_with_ignore_case("über"));
+    /// ```
+    pub fn starts_with_ignore_case(&self, prefix: &str) -> bool {
+        starts_with_folded(folded(self.as_str()), folded(prefix))
+    }
+
+    /// Does the [`Text`] end with `suffix`, ignoring case? Handles all of Unicode, lowercasing on the fly.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert!(Text::new("Ärger").ends_with_ignore_case("GER"));
+    /// ```
+    pub fn ends_with_ignore_case(&self, suffix: &str) -> bool {
+        starts_with_folded(folded_rev(self.as_str()), folded_rev(suffix))
+    }
+
+    /// Does the [`Text`] contain `needle`, ignoring case? Handles all of Unicode, lowercasing on the fly.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert!(Text::new("Grüße aus KÖLN").contains_ignore_case("köln"));
+    /// ```
+    pub fn contains_ignore_case(&self, needle: &str) -> bool {
+        let s = self.as_str();
+        needle.is_empty()
+            || s.char_indices()
+                .any(|(i, _)| starts_with_folded(folded(&s[i..]), folded(needle)))
+    }
+}
+
+fn folded_rev(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars().rev().flat_map(|c| c.to_lowercase().rev())
+}
+
+fn starts_with_folded<A, B>(mut haystack: A, mut prefix: B) -> bool
+where
+    A: Iterator<Item = char>,
+    B: Iterator<Item = char>,
+{
+    prefix.all(|p| haystack.next() == Some(p))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+    use std::cmp::Ordering;
+
+    #[test]
+    pub fn test_ignore_case() {
+        let pairs = [
+            ("Hello", "hELLO", Ordering::Equal),
+            ("abc", "ABD", Ordering::Less),
+            ("ab", "A", Ordering::Greater),
+            ("ΣΑΣ", "σασ", Ordering::Equal),
+            ("Ärger", "ärgern", Ordering::Less),
+            ("İ", "i\u{307}", Ordering::Equal),
+        ];
+        for (a, b, ord) in pairs.iter() {
+            let (a, b) = (Text::new(*a), Text::new(*b));
+            assert_eq!(*ord, a.cmp_ignore_case(&b), "{} {}", a, b);
+            assert_eq!(*ord == Ordering::Equal, a.eq_ignore_case(&b), "{} {}", a, b);
+        }
+    }
+
+    #[test]
+    pub fn test_affixes_ignore_case() {
+        let t = Text::new("GET /Index.HTML HTTP/1.1");
+        assert!(t.starts_with_ignore_ascii_case("get "));
+        assert!(!t.starts_with_ignore_ascii_case("post"));
+        assert!(t.ends_with_ignore_ascii_case("http/1.1"));
+        assert!(t.contains_ignore_ascii_case("index.html"));
+        assert!(!t.contains_ignore_ascii_case("index.htm "));
+        assert!(t.contains_ignore_ascii_case(""));
+        assert!(!Text::new("a").ends_with_ignore_ascii_case("ba"));
+        let u = Text::new("Die STRASSE in Ölde");
+        assert!(u.starts_with_ignore_case("die s"));
+        assert!(u.ends_with_ignore_case("öLDE"));
+        assert!(u.contains_ignore_case("strasse"));
+        assert!(!u.contains_ignore_case("straße"));
+        assert!(!u.ends_with_ignore_case("x ölde"));
+        assert!(Text::new("İ").ends_with_ignore_case("\u{307}"));
+    }
+}
@@ -0,0 +1,202 @@
+use crate::Text;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Arguments, Write};
+
+/// Deduplicates strings, handing out a shared [`Text`] for every distinct string content.
+///
+/// # Example
+/// ```
+/// use quetta::Interner;
+///
+/// let mut interner = Interner::new();
+/// let a = interner.intern("ident");
+/// let b = interner.intern("ident");
+/// assert_eq!(a, b);
+/// ```
+#[derive(Default)]
+pub struct Interner {
+    /// Every interned text with the tick of its last access.
+    texts: HashMap<Text, u64>,
+    /// The interned texts by their last access, only maintained if there are limits.
+    recency: BTreeMap<u64, Text>,
+    tick: u64,
+    bytes_used: usize,
+    hits: u64,
+    misses: u64,
+    max_len: Option<usize>,
+    max_bytes: Option<usize>,
+    buffer: String,
+}
+
+impl Interner {
+    /// Creates a new, empty [`Interner`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new, empty [`Interner`] holding at most `max_len` distinct texts of at most `max_bytes` bytes in total.
+    /// When a limit is exceeded, the least recently used texts are evicted, except for the most recently interned one.
+    /// Evicted texts stay valid, but interning the same string again creates a new buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Interner;
+    ///
+    /// let mut interner = Interner::with_limits(Some(2), None);
+    /// interner.intern("a");
+    /// interner.intern("b");
+    /// interner.intern("a");
+    /// interner.intern("c");
+    /// assert_eq!(2, interner.len());
+    /// assert!(interner.contains("a"));
+    /// assert!(!interner.contains("b"));
+    /// ```
+    pub fn with_limits(max_len: Option<usize>, max_bytes: Option<usize>) -> Self {
+        Self {
+            max_len,
+            max_bytes,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the interned [`Text`] for `s`, creating it if `s` has not been seen before.
+    pub fn intern(&mut self, s: &str) -> Text {
+        self.tick += 1;
+        let tick = self.tick;
+        let limited = self.max_len.is_some() || self.max_bytes.is_some();
+        if let Some((text, &last)) = self.texts.get_key_value(s) {
+            let text = text.clone();
+            self.hits += 1;
+            if limited {
+                self.recency.remove(&last);
+                self.recency.insert(tick, text.clone());
+                self.texts.insert(text.clone(), tick);
+            }
+            return text;
+        }
+        self.misses += 1;
+        let text = Text::new(s);
+        self.bytes_used += text.len();
+        self.texts.insert(text.clone(), tick);
+        if limited {
+            self.recency.insert(tick, text.clone());
+            self.evict();
+        }
+        text
+    }
+
+    /// Evicts the least recently used texts until the limits are met or only one text is left.
+    fn evict(&mut self) {
+        while self.texts.len() > 1
+            && (self.max_len.is_some_and(|max| self.texts.len() > max)
+                || self.max_bytes.is_some_and(|max| self.bytes_used > max))
+        {
+            if let Some((_, oldest)) = self.recency.pop_first() {
+                self.bytes_used -= oldest.len();
+                self.texts.remove(&oldest);
+            }
+        }
+    }
+
+    /// Has `s` been interned (and not been evicted since)? This does not count as an access.
+    pub fn contains(&self, s: &str) -> bool {
+        self.texts.contains_key(s)
+    }
+
+    /// Gets the number of distinct texts currently interned.
+    pub fn len(&self) -> usize {
+        self.texts.len()
+    }
+
+    /// Are no texts interned?
+    pub fn is_empty(&self) -> bool {
+        self.texts.is_empty()
+    }
+
+    /// Gets the total length in bytes of all texts currently interned.
+    pub fn bytes_used(&self) -> usize {
+        self.bytes_used
+    }
+
+    /// Gets the fraction of calls to [`Interner::intern`] that found an already interned text, or 0 if there were none.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// for word in "to be or not to be".split(' ') {
+    ///     interner.intern(word);
+    /// }
+    /// assert_eq!(2.0 / 6.0, interner.hit_rate());
+    /// assert_eq!(9, interner.bytes_used());
+    /// ```
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+
+    /// Formats `args` into the interner's reusable scratch buffer and interns the result.
+    /// No intermediate `String` is allocated, and if the formatted string has been interned
+    /// before, no allocation happens at all.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Interner;
+    ///
+    /// let mut interner = Interner::new();
+    /// let a = interner.intern_fmt(format_args!("tmp{}", 1));
+    /// let b = interner.intern("tmp1");
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn intern_fmt(&mut self, args: Arguments<'_>) -> Text {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+        buffer
+            .write_fmt(args)
+            .expect("a Display implementation returned an error unexpectedly");
+        let text = self.intern(&buffer);
+        self.buffer = buffer;
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Interner;
+
+    #[test]
+    pub fn test_intern_fmt() {
+        let mut interner = Interner::new();
+        let a = interner.intern_fmt(format_args!("{}_{}", "x", 42));
+        let b = interner.intern_fmt(format_args!("x_{}", 42));
+        let c = interner.intern_fmt(format_args!("y"));
+        assert_eq!("x_42", a.as_str());
+        assert_eq!(a.as_str().as_ptr(), b.as_str().as_ptr());
+        assert_eq!("y", c.as_str());
+    }
+
+    #[test]
+    pub fn test_limits() {
+        let mut interner = Interner::with_limits(None, Some(4));
+        assert!(interner.is_empty());
+        let ab = interner.intern("ab");
+        interner.intern("cd");
+        interner.intern("ab");
+        interner.intern("e");
+        assert_eq!((2, 3), (interner.len(), interner.bytes_used()));
+        assert!(interner.contains("ab") && !interner.contains("cd"));
+        assert_eq!(
+            ab.as_str().as_ptr(),
+            interner.intern("ab").as_str().as_ptr()
+        );
+        interner.intern("toolong");
+        assert_eq!((1, 7), (interner.len(), interner.bytes_used()));
+        assert_eq!("ab", ab.as_str());
+        assert_eq!(0.0, Interner::new().hit_rate());
+    }
+}
@@ -0,0 +1,134 @@
+use crate::Text;
+use std::fmt::{Display, Formatter, Write};
+
+/// Is `c` a character that cannot be told apart from others (or from nothing) when printed?
+fn is_invisible(c: char) -> bool {
+    c.is_control()
+        || (c.is_whitespace() && c != ' ')
+        || matches!(c, '\u{ad}' | '\u{200b}'..='\u{200f}' | '\u{2060}'..='\u{2064}' | '\u{feff}')
+}
+
+/// A [`Text`] displayed with invisible characters escaped, created by [`Text::show_invisibles`].
+#[derive(Debug, Clone)]
+pub struct ShowInvisibles<'a>(&'a Text);
+
+impl Display for ShowInvisibles<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = self.0.as_str();
+        let mut copied = 0;
+        for (i, c) in s.char_indices() {
+            if c != '\\' && !is_invisible(c) {
+                continue;
+            }
+            f.write_str(&s[copied..i])?;
+            match c {
+                '\\' => f.write_str("\\\\")?,
+                '\t' => f.write_str("\\t")?,
+                '\r' => f.write_str("\\r")?,
+                // Keep the line break itself, so that multi-line texts stay readable
+                '\n' => f.write_str("\\n\n")?,
+                _ => write!(f, "\\u{{{:x}}}", c as u32)?,
+            }
+            copied = i + c.len_utf8();
+        }
+        f.write_str(&s[copied..])
+    }
+}
+
+/// The bytes of a [`Text`] displayed as a hex dump, created by [`Text::hexdump`].
+#[derive(Debug, Clone)]
+pub struct HexDump<'a>(&'a Text);
+
+impl Display for HexDump<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (line, bytes) in self.0.as_str().as_bytes().chunks(16).enumerate() {
+            write!(f, "{:08x} ", line * 16)?;
+            for i in 0..16 {
+                if i % 8 == 0 {
+                    f.write_char(' ')?;
+                }
+                match bytes.get(i) {
+                    Some(b) => write!(f, "{:02x} ", b)?,
+                    None => f.write_str("   ")?,
+                }
+            }
+            f.write_str(" |")?;
+            for b in bytes {
+                let c = if b.is_ascii_graphic() || *b == b' ' {
+                    *b as char
+                } else {
+                    '.'
+                };
+                f.write_char(c)?;
+            }
+            f.write_str("|\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl Text {
+    /// Displays the [`Text`] with invisible characters escaped: tabs, carriage returns and line feeds as `\t`, `\r` and `\n`,
+    /// and other control, whitespace and zero-width characters (like NBSP) as `\u{...}`. Backslashes are doubled,
+    /// and line feeds are followed by an actual line break.
+    /// Useful for debugging texts that look equal, but are not.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("a\tb\u{a0}c\r\n");
+    /// assert_eq!("a\\tb\\u{a0}c\\r\\n\n", text.show_invisibles().to_string());
+    /// ```
+    pub fn show_invisibles(&self) -> ShowInvisibles<'_> {
+        ShowInvisibles(self)
+    }
+
+    /// Displays the bytes of the [`Text`] as a hex dump with 16 bytes per line, followed by their printable ASCII characters.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("quetta\tñ");
+    /// assert_eq!(
+    ///     "00000000  71 75 65 74 74 61 09 c3  b1                       |quetta...|\n",
+    ///     text.hexdump().to_string()
+    /// );
+    /// ```
+    pub fn hexdump(&self) -> HexDump<'_> {
+        HexDump(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_show_invisibles() {
+        let t = Text::new("x\\a\u{200b}b\u{1b}\nc").slice(1, 10);
+        assert_eq!(
+            "\\\\a\\u{200b}b\\u{1b}\\n\nc",
+            t.show_invisibles().to_string()
+        );
+        let plain = Text::new("plain text");
+        assert_eq!("plain text", plain.show_invisibles().to_string());
+    }
+
+    #[test]
+    pub fn test_hexdump() {
+        let t = Text::new("0123456789abcdefXY");
+        let dump = t.hexdump().to_string();
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(
+            "00000000  30 31 32 33 34 35 36 37  38 39 61 62 63 64 65 66  |0123456789abcdef|",
+            lines[0]
+        );
+        assert_eq!(
+            "00000010  58 59                                             |XY|",
+            lines[1]
+        );
+        assert_eq!("", Text::default().hexdump().to_string());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{Interner, Text};
+use serde_json::{Number, Value};
+use std::collections::BTreeMap;
+
+/// A JSON value whose strings and object keys are [`Text`]s, converted from a `serde_json::Value`.
+/// Parsed documents that are kept around can share their strings, especially when converted with
+/// [`TextValue::from_json_interned`]. Requires the `serde_json` feature.
+///
+/// # Example
+/// ```
+/// use quetta::{Interner, TextValue};
+/// use serde_json::json;
+///
+/// let mut interner = Interner::new();
+/// let config = json!([{ "level": "debug" }, { "level": "debug" }]);
+/// let value = TextValue::from_json_interned(&config, &mut interner);
+/// if let TextValue::Array(items) = value {
+///     let first = items[0].get("level").and_then(TextValue::as_text).unwrap();
+///     let second = items[1].get("level").and_then(TextValue::as_text).unwrap();
+///     assert_eq!(first.as_str().as_ptr(), second.as_str().as_ptr());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextValue {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(Text),
+    Array(Vec<TextValue>),
+    Object(BTreeMap<Text, TextValue>),
+}
+
+impl TextValue {
+    /// Converts `value`, copying every string into its own [`Text`].
+    pub fn from_json(value: &Value) -> Self {
+        Self::convert(value, &mut |s| Text::new(s))
+    }
+
+    /// Converts `value`, interning all strings and object keys with `interner`, so that repeated strings share one buffer.
+    pub fn from_json_interned(value: &Value, interner: &mut Interner) -> Self {
+        Self::convert(value, &mut |s| interner.intern(s))
+    }
+
+    fn convert<F: FnMut(&str) -> Text>(value: &Value, text: &mut F) -> Self {
+        match value {
+            Value::Null => TextValue::Null,
+            Value::Bool(b) => TextValue::Bool(*b),
+            Value::Number(n) => TextValue::Number(n.clone()),
+            Value::String(s) => TextValue::String(text(s)),
+            Value::Array(items) => {
+                TextValue::Array(items.iter().map(|v| Self::convert(v, text)).collect())
+            }
+            Value::Object(map) => TextValue::Object(
+                map.iter()
+                    .map(|(k, v)| (text(k), Self::convert(v, text)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Gets the string, if this is a [`TextValue::String`].
+    pub fn as_text(&self) -> Option<&Text> {
+        match self {
+            TextValue::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Gets the value of `key`, if this is a [`TextValue::Object`] containing it.
+    pub fn get(&self, key: &str) -> Option<&TextValue> {
+        match self {
+            TextValue::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+}
+
+impl From<&Value> for TextValue {
+    fn from(value: &Value) -> Self {
+        Self::from_json(value)
+    }
+}
+
+fn flatten(
+    value: &Value,
+    path: &mut String,
+    interner: &mut Interner,
+    map: &mut BTreeMap<Text, Text>,
+) {
+    let leaf = match value {
+        Value::Null => return,
+        Value::Bool(b) => interner.intern_fmt(format_args!("{}", b)),
+        Value::Number(n) => interner.intern_fmt(format_args!("{}", n)),
+        Value::String(s) => interner.intern(s),
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                let len = path.len();
+                push_segment(path, &i.to_string());
+                flatten(item, path, interner, map);
+                path.truncate(len);
+            }
+            return;
+        }
+        Value::Object(object) => {
+            for (key, item) in object {
+                let len = path.len();
+                push_segment(path, key);
+                flatten(item, path, interner, map);
+                path.truncate(len);
+            }
+            return;
+        }
+    };
+    map.insert(Text::new(path.as_str()), leaf);
+}
+
+fn push_segment(path: &mut String, segment: &str) {
+    if !path.is_empty() {
+        path.push('.');
+    }
+    path.push_str(segment);
+}
+
+/// Flattens a JSON document into a map from dotted paths (like `server.ports.0`) to the text of its scalar leaves,
+/// as is common for configuration lookups. Strings are taken as they are, numbers and booleans are formatted,
+/// and `null`s are left out. Equal values share one interned buffer. Requires the `serde_json` feature.
+///
+/// # Example
+/// ```
+/// use quetta::json_to_text_map;
+/// use serde_json::json;
+///
+/// let config = json!({ "server": { "host": "localhost", "ports": [80, 443] }, "debug": true });
+/// let map = json_to_text_map(&config);
+/// assert_eq!("localhost", map["server.host"].as_str());
+/// assert_eq!("443", map["server.ports.1"].as_str());
+/// assert_eq!("true", map["debug"].as_str());
+/// ```
+pub fn json_to_text_map(value: &Value) -> BTreeMap<Text, Text> {
+    let mut map = BTreeMap::new();
+    flatten(value, &mut String::new(), &mut Interner::new(), &mut map);
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{json_to_text_map, Text, TextValue};
+    use serde_json::json;
+
+    #[test]
+    pub fn test_text_value() {
+        let json = json!({ "a": [1, "x", null, false], "b": { "c": "x" } });
+        let value = TextValue::from(&json);
+        let inner = value.get("b").and_then(|b| b.get("c"));
+        assert_eq!(Some(&Text::new("x")), inner.and_then(TextValue::as_text));
+        match value.get("a") {
+            Some(TextValue::Array(items)) => {
+                assert_eq!(TextValue::Number(1.into()), items[0]);
+                assert_eq!(TextValue::Null, items[2]);
+                assert_eq!(TextValue::Bool(false), items[3]);
+            }
+            other => panic!("Expected an array, got {:?}", other),
+        }
+        assert_eq!(None, value.get("z"));
+    }
+
+    #[test]
+    pub fn test_json_to_text_map() {
+        let json = json!({ "a": [{ "b": "x" }, null], "c": "x", "d": 1.5 });
+        let map = json_to_text_map(&json);
+        let keys: Vec<&str> = map.keys().map(Text::as_str).collect();
+        assert_eq!(vec!["a.0.b", "c", "d"], keys);
+        assert_eq!(map["a.0.b"].as_str().as_ptr(), map["c"].as_str().as_ptr());
+        assert_eq!("1.5", map["d"].as_str());
+        assert!(json_to_text_map(&json!(null)).is_empty());
+        assert_eq!("s", json_to_text_map(&json!("s"))[""].as_str());
+    }
+}
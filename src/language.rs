@@ -0,0 +1,34 @@
+use crate::Text;
+use whatlang::Lang;
+
+impl Text {
+    /// Detects the natural language the [`Text`] is written in, using `whatlang`.
+    /// Returns `None` if no language could be detected, e.g. for empty texts or texts without letters.
+    /// Detection is a statistical guess and becomes unreliable for short texts.
+    /// Requires the `whatlang` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{Lang, Text};
+    ///
+    /// let text = Text::new("Ich bin ein Berliner und das ist ein deutscher Satz.");
+    /// assert_eq!(Some(Lang::Deu), text.detect_language());
+    /// ```
+    pub fn detect_language(&self) -> Option<Lang> {
+        whatlang::detect_lang(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Lang, Text};
+
+    #[test]
+    pub fn test_detect_language() {
+        let t = Text::new("xThe quick brown fox jumps over the lazy dog and runs away.x");
+        assert_eq!(Some(Lang::Eng), t.slice(1, t.len() - 1).detect_language());
+        let russian = Text::new("Съешь же ещё этих мягких французских булок, да выпей чаю");
+        assert_eq!(Some(Lang::Rus), russian.detect_language());
+        assert_eq!(None, Text::new("1234 !?").detect_language());
+    }
+}
@@ -1,6 +1,6 @@
 //! **quetta** (from the Quenya word for "word") is a library providing simple
 //! immutable strings in Rust.
-//! Essentially, it is a wrapper around `Arc<str>`, but with support for slicing and compatibility features
+//! Essentially, it is a wrapper around an `Arc<str>`-like shared buffer, but with support for slicing and compatibility features
 //! with `&str`.
 //!
 //! The primary type provided by **quetta** is [`Text`].
@@ -15,33 +15,269 @@
 //! let s1 = t.slice(0, 2);
 //! assert_eq!("a.", s1.as_str());
 //! ```
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::Index;
 use std::slice::SliceIndex;
 use std::str::FromStr;
-use std::sync::Arc;
 
-#[derive(Clone)]
-struct IString(Arc<str>);
+mod ansi;
+mod any;
+mod append;
+#[cfg(feature = "unicode-bidi")]
+mod bidi;
+mod borrowed;
+mod builder;
+mod bytes;
+mod casing;
+mod chars;
+mod class;
+mod columns;
+mod comments;
+mod compose;
+#[cfg(feature = "unicode-security")]
+mod confusable;
+mod dedup;
+mod distance;
+#[cfg(feature = "encoding_rs")]
+mod encoding;
+mod guard;
+mod hinted;
+#[cfg(feature = "html")]
+mod html;
+mod ignore_case;
+mod interner;
+mod invisibles;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "whatlang")]
+mod language;
+mod lines;
+mod log;
+mod markdown;
+mod mask;
+#[cfg(feature = "encoding_rs")]
+mod mime;
+mod normalize;
+mod numeric;
+mod ops;
+mod output;
+mod parallel;
+mod paths;
+mod quoted;
+mod regex_escape;
+mod rules;
+#[cfg(feature = "unicode-script")]
+mod script;
+#[cfg(feature = "aho-corasick")]
+mod search;
+#[cfg(feature = "subtle")]
+mod secret;
+mod seq;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shared;
+mod shingles;
+mod spans;
+mod stats;
+mod stream;
+mod table;
+mod tail;
+mod tokenize;
+#[cfg(feature = "deunicode")]
+mod transliterate;
+mod uri;
+mod version;
+
+pub use ansi::{AnsiSegment, AnsiStyle, Highlighted};
+pub use any::AnyText;
+pub use append::AppendOnlyText;
+#[cfg(feature = "unicode-bidi")]
+pub use bidi::BidiRun;
+pub use borrowed::TextRef;
+pub use builder::{LimitExceededError, TextBuilder};
+pub use bytes::{Bytes, InvalidUtf8Error};
+pub use casing::Case;
+pub use chars::{CharIndexedText, CharRange};
+pub use class::CharClass;
+pub use columns::ColumnUnit;
+pub use comments::CommentStyle;
+pub use compose::Composer;
+pub use dedup::TextDedup;
+#[cfg(feature = "encoding_rs")]
+pub use encoding::UnknownEncodingError;
+pub use guard::TextGuard;
+pub use hinted::HintedText;
+pub use interner::Interner;
+pub use invisibles::{HexDump, ShowInvisibles};
+#[cfg(feature = "serde_json")]
+pub use json::{json_to_text_map, TextValue};
+pub use lines::LineTable;
+pub use log::LogRecord;
+pub use markdown::Inline;
+pub use normalize::{CollapseWhitespace, Lowercase, NormalizedText, Normalizer, Trim};
+pub use numeric::NotNumericError;
+pub use ops::TextOps;
+pub use parallel::ChunkBoundary;
+pub use quoted::QuoteEscape;
+pub use rules::RuleSet;
+#[cfg(feature = "aho-corasick")]
+pub use search::TextSearcher;
+#[cfg(feature = "subtle")]
+pub use secret::SecretText;
+pub use seq::TextSeq;
+#[cfg(feature = "serde")]
+pub use serialize::InternedTextSeed;
+pub use stats::TextStats;
+pub use stream::StreamSearcher;
+pub use table::{TableDecodeError, TextTable};
+pub use tokenize::TokenSpec;
+#[cfg(feature = "unicode-script")]
+pub use unicode_script::Script;
+pub use uri::UriParts;
+#[cfg(feature = "whatlang")]
+pub use whatlang::Lang;
+
+use shared::IString;
+
+/// The type used to store slice offsets. With the `u32-offsets` feature, this is `u32`, which makes
+/// [`Text`] smaller, but limits backing buffers to [`Text::MAX_LEN`] bytes.
+#[cfg(feature = "u32-offsets")]
+type Offset = u32;
+#[cfg(not(feature = "u32-offsets"))]
+type Offset = usize;
+
+/// Converts an offset into a backing buffer, which is at most [`Text::MAX_LEN`] bytes long and so always fits.
+#[allow(clippy::unnecessary_cast, clippy::absurd_extreme_comparisons)]
+fn to_offset(n: usize) -> Offset {
+    debug_assert!(n <= Text::MAX_LEN);
+    n as Offset
+}
+
+/// Checks that a string of length `len` can back a [`Text`].
+#[allow(clippy::absurd_extreme_comparisons)]
+fn check_len(len: usize) -> Result<(), TooLargeError> {
+    if len > Text::MAX_LEN {
+        Err(TooLargeError { len })
+    } else {
+        Ok(())
+    }
+}
+
+#[allow(clippy::unnecessary_cast)]
+fn from_offset(n: Offset) -> usize {
+    n as usize
+}
 
+/// A slice of a shared buffer. An entire buffer is simply a slice starting at 0 and spanning its whole length.
+/// This is three words large, or two with the `u32-offsets` feature.
 #[derive(Clone)]
-enum TextData {
-    Entire(IString),
-    Slice {
-        string: IString,
+struct TextData {
+    string: IString,
+    start: Offset,
+    len: Offset,
+}
+
+/// The error returned when slicing a [`Text`] with an invalid range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    /// The range exceeds the bounds of the [`Text`].
+    OutOfBounds {
+        text_len: usize,
         start: usize,
         len: usize,
     },
+    /// The range ends before it starts.
+    InvertedRange { start: usize, end: usize },
+    /// The given index does not lie on a `char` boundary.
+    NotCharBoundary { index: usize },
+}
+
+impl Display for SliceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SliceError::OutOfBounds { text_len, start, len } => write!(f, "Slice index out of bounds: Length of string is {}, but slice start was {} and slice length was {}", text_len, start, len),
+            SliceError::InvertedRange { start, end } => write!(f, "Slice index starts at {} but ends at {}", start, end),
+            SliceError::NotCharBoundary { index } => write!(f, "Slice index {} is not on a char boundary", index),
+        }
+    }
+}
+
+impl std::error::Error for SliceError {}
+
+/// The error returned when creating a [`Text`] longer than [`Text::MAX_LEN`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooLargeError {
+    len: usize,
+}
+
+impl TooLargeError {
+    /// Gets the length of the rejected string.
+    pub fn string_len(&self) -> usize {
+        self.len
+    }
+}
+
+impl Display for TooLargeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "String of length {} exceeds the maximum length of {}",
+            self.len,
+            Text::MAX_LEN
+        )
+    }
 }
 
+impl std::error::Error for TooLargeError {}
+
+/// The error returned by [`Text::lift_many_strict`] when the lifted function yields a `&str` that is not part of the [`Text`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiftError {
+    index: usize,
+    item: String,
+}
+
+impl LiftError {
+    /// Gets the position of the offending item in the iterator.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the offending item.
+    pub fn item(&self) -> &str {
+        &self.item
+    }
+}
+
+impl Display for LiftError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Item {} ({:?}) is not a slice of the lifted text",
+            self.index, self.item
+        )
+    }
+}
+
+impl std::error::Error for LiftError {}
+
 /// The primary type of **quetta**, representing an immutable sequence of characters.
 /// Internally, this can be either a full string or a slice into another [`Text`].
 /// Can be cloned cheaply.
+///
+/// [`Text`] is [`Text::SIZE`] bytes large, and thanks to its non-null pointer, `Option<Text>` is no larger.
+/// Its reference count is atomic, like that of `Arc<str>`, so it is `Send` and `Sync` and can be shared across threads and tasks.
 pub struct Text(TextData);
 
+const _: () = assert!(std::mem::size_of::<Text>() == Text::SIZE);
+const _: () = assert!(std::mem::size_of::<Option<Text>>() == Text::SIZE);
+
+const fn assert_send_sync<T: Send + Sync>() {}
+const _: () = assert_send_sync::<Text>();
+
 impl Clone for Text {
     fn clone(&self) -> Self {
         Self(self.0.clone())
@@ -50,22 +286,27 @@ impl Clone for Text {
 
 impl Default for Text {
     fn default() -> Self {
-        let empty = IString(String::new().into());
-        Self(TextData::Entire(empty))
+        Self::from_istring(IString::new(""))
     }
 }
 
 impl Debug for Text {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let s: &str = self.into();
-        write!(f, "{}", s)
+        f.pad(self.as_str())
     }
 }
 
+/// Honors width, precision, alignment and fill like `str` does, e.g. `{:>10}` or `{:.5}`.
+/// Without those, the [`Text`] is written in chunks of bounded size, see [`Text::write_to`].
 impl Display for Text {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let s: &str = self.into();
-        write!(f, "{}", s)
+        if f.width().is_some() || f.precision().is_some() {
+            return f.pad(self.as_str());
+        }
+        for chunk in output::write_chunks(self.as_str()) {
+            f.write_str(chunk)?;
+        }
+        Ok(())
     }
 }
 
@@ -93,7 +334,7 @@ impl Hash for Text {
 
 impl PartialOrd for Text {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.as_str().partial_cmp(other.as_str())
+        Some(self.cmp(other))
     }
 }
 
@@ -109,15 +350,16 @@ impl<'a> From<&'a Text> for String {
     }
 }
 
+impl From<Text> for String {
+    fn from(text: Text) -> Self {
+        text.into_string()
+    }
+}
+
 impl<'a> From<&'a Text> for &'a str {
     fn from(t: &'a Text) -> Self {
-        match &t.0 {
-            TextData::Entire(s) => &*s.0,
-            TextData::Slice { string, start, len } => {
-                let s = &*string.0;
-                &s[*start..*start + *len]
-            }
-        }
+        let (root, start, len) = t.raw_parts();
+        &root[start..start + len]
     }
 }
 
@@ -139,7 +381,7 @@ impl Borrow<str> for Text {
     }
 }
 
-impl<'a, Idx: SliceIndex<str>> Index<Idx> for &'a Text {
+impl<Idx: SliceIndex<str>> Index<Idx> for &Text {
     type Output = Idx::Output;
 
     fn index(&self, index: Idx) -> &Self::Output {
@@ -147,7 +389,7 @@ impl<'a, Idx: SliceIndex<str>> Index<Idx> for &'a Text {
     }
 }
 
-impl<'a, Idx: SliceIndex<str>> Index<Idx> for Text {
+impl<Idx: SliceIndex<str>> Index<Idx> for Text {
     type Output = Idx::Output;
 
     fn index(&self, index: Idx) -> &Self::Output {
@@ -156,10 +398,103 @@ impl<'a, Idx: SliceIndex<str>> Index<Idx> for Text {
 }
 
 impl Text {
+    /// The maximum length of a [`Text`] in bytes.
+    /// This is `u32::MAX` with the `u32-offsets` feature (or less on 16-bit targets) and `usize::MAX` otherwise.
+    #[cfg(feature = "u32-offsets")]
+    pub const MAX_LEN: usize = if usize::BITS > u32::BITS {
+        u32::MAX as usize
+    } else {
+        usize::MAX
+    };
+    /// The maximum length of a [`Text`] in bytes.
+    /// This is `u32::MAX` with the `u32-offsets` feature (or less on 16-bit targets) and `usize::MAX` otherwise.
+    #[cfg(not(feature = "u32-offsets"))]
+    pub const MAX_LEN: usize = usize::MAX;
+
+    /// The size of a [`Text`] in bytes: a pointer and two offsets. That is three machine words,
+    /// or two on 64-bit targets with the `u32-offsets` feature.
+    pub const SIZE: usize = std::mem::size_of::<usize>() + 2 * std::mem::size_of::<Offset>();
+
     /// Creates a new [`Text`] by copying the provided slice.
+    /// Will panic if the slice is longer than [`Text::MAX_LEN`].
     pub fn new<'a, I: Into<&'a str>>(s: I) -> Self {
-        let inner = IString(Arc::from(s.into()));
-        Self(TextData::Entire(inner))
+        match Self::try_new(s) {
+            Ok(text) => text,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Creates a new [`Text`] by copying the provided slice.
+    /// Returns an error if the slice is longer than [`Text::MAX_LEN`], which can only happen with the `u32-offsets` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!("abc", Text::try_new("abc").unwrap().as_str());
+    /// ```
+    pub fn try_new<'a, I: Into<&'a str>>(s: I) -> Result<Self, TooLargeError> {
+        let s = s.into();
+        check_len(s.len())?;
+        Ok(Self::from_istring(IString::new(s)))
+    }
+
+    /// Creates a new [`Text`] by copying the provided slice into a buffer that is overwritten with zeros
+    /// once the last [`Text`] referring to it is dropped, so that secrets do not linger in freed memory.
+    /// Copies made from it, e.g. by [`Text::into_string`], are not zeroized.
+    /// Will panic if the slice is longer than [`Text::MAX_LEN`]. Requires the `zeroize` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let token = Text::new_sensitive("Bearer abc123");
+    /// let credentials = token.slice(7, 13);
+    /// drop(token);
+    /// assert_eq!("abc123", credentials.as_str());
+    /// ```
+    #[cfg(feature = "zeroize")]
+    pub fn new_sensitive(s: &str) -> Self {
+        if let Err(e) = check_len(s.len()) {
+            panic!("{}", e);
+        }
+        Self::from_istring(IString::new_sensitive(s))
+    }
+
+    /// Creates a [`Text`] sharing the string held by `owner`, without copying it.
+    /// The owner is kept alive (and never mutated) until the last [`Text`] referring to it is dropped.
+    /// This allows string data to live in memory managed by the caller, e.g. a `String`, a memory-mapped file
+    /// or a region of an arena, as long as the owner keeps that memory alive.
+    /// Will panic if the string is longer than [`Text::MAX_LEN`].
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    /// use std::sync::Arc;
+    ///
+    /// let arena: Arc<str> = Arc::from("fn main() {}");
+    /// let source = Text::from_owner(arena.clone());
+    /// assert_eq!(arena.as_ptr(), source.as_str().as_ptr());
+    ///
+    /// let owned = String::from("no copy");
+    /// let ptr = owned.as_ptr();
+    /// assert_eq!(ptr, Text::from_owner(owned).as_str().as_ptr());
+    /// ```
+    pub fn from_owner<O: AsRef<str> + Send + Sync + 'static>(owner: O) -> Self {
+        let string = IString::from_owner(owner);
+        if let Err(e) = check_len(string.as_str().len()) {
+            panic!("{}", e);
+        }
+        Self::from_istring(string)
+    }
+
+    fn from_istring(string: IString) -> Self {
+        let len = to_offset(string.as_str().len());
+        Self(TextData {
+            string,
+            start: 0,
+            len,
+        })
     }
 
     /// Gets the [`Text`] as a slice.
@@ -167,8 +502,69 @@ impl Text {
         self.into()
     }
 
+    /// Gets the [`Text`] as a slice, returning an error instead of panicking
+    /// if the [`Text`] is a slice that is out of bounds or not on `char` boundaries.
+    /// This can only happen for slices created with unchecked APIs.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("qwerty").slice(1, 3);
+    /// assert_eq!(Ok("we"), text.as_str_checked());
+    /// ```
+    pub fn as_str_checked(&self) -> Result<&str, SliceError> {
+        let (root, start, len) = self.raw_parts();
+        match start.checked_add(len) {
+            Some(end) if end <= root.len() => root.get(start..end).ok_or_else(|| {
+                let index = if root.is_char_boundary(start) {
+                    end
+                } else {
+                    start
+                };
+                SliceError::NotCharBoundary { index }
+            }),
+            _ => Err(SliceError::OutOfBounds {
+                text_len: root.len(),
+                start,
+                len,
+            }),
+        }
+    }
+
+    /// Gets the [`Text`] as a slice, never panicking.
+    /// If the [`Text`] is an invalid slice (see [`Text::as_str_checked`]), the range is clamped to the
+    /// backing buffer and partial characters are replaced with `U+FFFD`, which requires a copy.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("qwerty").slice(1, 3);
+    /// assert_eq!(Cow::Borrowed("we"), text.as_str_lossy());
+    /// ```
+    pub fn as_str_lossy(&self) -> Cow<'_, str> {
+        match self.as_str_checked() {
+            Ok(s) => Cow::Borrowed(s),
+            Err(_) => {
+                let (root, start, len) = self.raw_parts();
+                let bytes = root.as_bytes();
+                let start = start.min(bytes.len());
+                let end = start.saturating_add(len).min(bytes.len());
+                String::from_utf8_lossy(&bytes[start..end])
+            }
+        }
+    }
+
+    /// Returns the backing string together with the start and length of this [`Text`] within it.
+    fn raw_parts(&self) -> (&str, usize, usize) {
+        let TextData { string, start, len } = &self.0;
+        (string.as_str(), from_offset(*start), from_offset(*len))
+    }
+
     /// Creates another [`Text`] with a provided start code point and length.
-    /// Will panic if the substring exceeds the [`Text`]'s bounds.
+    /// Will panic if the substring exceeds the [`Text`]'s bounds or does not start and end on a `char` boundary.
     ///
     /// # Example
     /// ```
@@ -179,29 +575,75 @@ impl Text {
     /// assert_eq!("qw", sub.as_str());
     /// ```
     pub fn substring(&self, start: usize, len: usize) -> Text {
-        if start + len > self.len() {
-            panic!("Slice index out of bounds: Length of string is {}, but slice start was {} and slice length was {}", self.len(), start, len)
+        match self.try_substring(start, len) {
+            Ok(text) => text,
+            Err(e) => panic!("{}", e),
         }
-        match &self.0 {
-            TextData::Entire(s) => Self(TextData::Slice {
-                string: s.clone(),
-                start,
-                len,
-            }),
-            TextData::Slice {
-                string,
-                start: s2,
-                len: _,
-            } => Self(TextData::Slice {
-                string: string.clone(),
-                start: s2 + start,
-                len,
-            }),
+    }
+
+    /// Creates another [`Text`] with a provided start code point and length.
+    /// Returns an error if the substring exceeds the [`Text`]'s bounds or does not start and end on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{SliceError, Text};
+    ///
+    /// let text = Text::new("añb");
+    /// assert_eq!("añ", text.try_substring(0, 3).unwrap().as_str());
+    /// assert_eq!(Err(SliceError::NotCharBoundary { index: 2 }), text.try_substring(0, 2));
+    /// ```
+    pub fn try_substring(&self, start: usize, len: usize) -> Result<Text, SliceError> {
+        let s = self.as_str();
+        let end = match start.checked_add(len) {
+            Some(end) if end <= s.len() => end,
+            _ => {
+                return Err(SliceError::OutOfBounds {
+                    text_len: s.len(),
+                    start,
+                    len,
+                })
+            }
+        };
+        if !s.is_char_boundary(start) {
+            return Err(SliceError::NotCharBoundary { index: start });
+        }
+        if !s.is_char_boundary(end) {
+            return Err(SliceError::NotCharBoundary { index: end });
         }
+        Ok(self.substring_raw(start, len))
+    }
+
+    /// Creates another [`Text`] with a provided start code point and length, without checking
+    /// bounds or `char` boundaries.
+    /// Intended for hot loops (e.g. lexers) that have already validated their offsets.
+    ///
+    /// # Safety
+    /// `start..start + len` must lie within the bounds of `self` and both ends must be on `char` boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("qwerty");
+    /// let sub = unsafe { text.substring_unchecked(2, 3) };
+    /// assert_eq!("ert", sub.as_str());
+    /// ```
+    pub unsafe fn substring_unchecked(&self, start: usize, len: usize) -> Text {
+        self.substring_raw(start, len)
+    }
+
+    /// Creates the slice without any validation; callers must ensure that the range is in bounds
+    /// and lies on `char` boundaries.
+    fn substring_raw(&self, start: usize, len: usize) -> Text {
+        Self(TextData {
+            string: self.0.string.clone(),
+            start: to_offset(from_offset(self.0.start) + start),
+            len: to_offset(len),
+        })
     }
 
     /// Creates another [`Text`] with a provided start code point and end code point, similar to the slice operator.
-    /// Will panic if the slice exceeds the [`Text`]'s bounds.
+    /// Will panic if the slice exceeds the [`Text`]'s bounds or does not start and end on a `char` boundary.
     ///
     /// # Example
     /// ```
@@ -212,7 +654,309 @@ impl Text {
     /// assert_eq!("we", sub.as_str());
     /// ```
     pub fn slice(&self, start: usize, end: usize) -> Text {
-        self.substring(start, end - start)
+        match self.try_slice(start, end) {
+            Ok(text) => text,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Creates another [`Text`] with a provided start code point and end code point, similar to the slice operator.
+    /// Returns an error if the slice exceeds the [`Text`]'s bounds or does not start and end on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("qwerty");
+    /// assert_eq!("we", text.try_slice(1, 3).unwrap().as_str());
+    /// assert!(text.try_slice(3, 1).is_err());
+    /// assert!(text.try_slice(3, 7).is_err());
+    /// ```
+    pub fn try_slice(&self, start: usize, end: usize) -> Result<Text, SliceError> {
+        match end.checked_sub(start) {
+            Some(len) => self.try_substring(start, len),
+            None => Err(SliceError::InvertedRange { start, end }),
+        }
+    }
+
+    /// Gets the length of the buffer backing this [`Text`], which is kept alive as long as `self` is.
+    /// For slices, this can be much larger than [`Text::len`].
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("qwerty");
+    /// assert_eq!(6, text.slice(1, 3).backing_len());
+    /// ```
+    pub fn backing_len(&self) -> usize {
+        self.raw_parts().0.len()
+    }
+
+    /// Does the [`Text`] span its whole backing buffer, i.e. is it not a slice of a larger [`Text`]?
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("qwerty");
+    /// assert!(text.is_root());
+    /// assert!(!text.slice(1, 3).is_root());
+    /// ```
+    pub fn is_root(&self) -> bool {
+        let (root, start, len) = self.raw_parts();
+        start == 0 && len == root.len()
+    }
+
+    /// Gets the [`Text`] enclosing this slice, which is the whole backing buffer.
+    /// Slices do not remember the intermediate slices they were taken from,
+    /// so the parent of a slice of a slice is the root as well. The parent of a root is itself.
+    /// Together with [`Text::offset_in_parent`], this allows walking from a token back to its surroundings.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let source = Text::new("let x = 1;\nlet y = 2;");
+    /// let token = source.slice(15, 16);
+    /// let lines = token.parent().random_access_lines();
+    /// let line = lines.line_of_offset(token.offset_in_parent()).unwrap();
+    /// assert_eq!("let y = 2;", lines.line(line).unwrap().as_str());
+    /// ```
+    pub fn parent(&self) -> Text {
+        Self::from_istring(self.0.string.clone())
+    }
+
+    /// Gets the byte offset of the [`Text`] within its [`Text::parent`].
+    pub fn offset_in_parent(&self) -> usize {
+        self.raw_parts().1
+    }
+
+    /// Is `other` a slice of the same backing buffer that lies within `self`? This check takes constant time
+    /// and does not compare any characters.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let source = Text::new("let x = 1;");
+    /// assert!(source.contains_slice(&source.slice(4, 5)));
+    /// assert!(!source.slice(0, 3).contains_slice(&source.slice(4, 5)));
+    /// assert!(!source.contains_slice(&Text::new("x")));
+    /// ```
+    pub fn contains_slice(&self, other: &Text) -> bool {
+        self.shared_offset_of(other).is_some()
+    }
+
+    /// Gets the byte offset of `other` within `self`. If `other` is a slice of the same backing buffer
+    /// (see [`Text::contains_slice`]), this takes constant time.
+    /// Otherwise, the first occurrence of `other` is searched for, returning `None` if there is none.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let source = Text::new("x = x + 1;");
+    /// let tokens: Vec<Text> = source.split_terminator_char(' ').collect();
+    /// assert_eq!(Some(4), source.byte_offset_of(&tokens[2]));
+    /// assert_eq!(Some(0), source.byte_offset_of(&Text::new("x")));
+    /// assert_eq!(None, source.byte_offset_of(&Text::new("y")));
+    /// ```
+    pub fn byte_offset_of(&self, other: &Text) -> Option<usize> {
+        self.shared_offset_of(other)
+            .or_else(|| self.as_str().find(other.as_str()))
+    }
+
+    /// Joins `a` and `b` without copying if `b` directly follows `a` in the same backing buffer,
+    /// e.g. to coalesce runs of tokens. Returns `None` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let source = Text::new("a + b");
+    /// let (a, plus) = (source.slice(0, 1), source.slice(1, 3));
+    /// assert_eq!("a +", Text::checked_concat_adjacent(&a, &plus).unwrap().as_str());
+    /// assert!(Text::checked_concat_adjacent(&plus, &a).is_none());
+    /// ```
+    pub fn checked_concat_adjacent(a: &Text, b: &Text) -> Option<Text> {
+        let (root, start, len) = a.raw_parts();
+        let (_, other_start, other_len) = b.raw_parts();
+        if a.same_buffer(b) && start + len == other_start {
+            assert!(other_start + other_len <= root.len());
+            Some(a.substring_raw(0, len + other_len))
+        } else {
+            None
+        }
+    }
+
+    /// Compares the positions of two slices of the same backing buffer, by start offset and then by length,
+    /// without comparing any characters. Returns `None` if they do not share a buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let source = Text::new("b a c");
+    /// let mut tokens: Vec<Text> = source.split_terminator_char(' ').collect();
+    /// tokens.sort();
+    /// tokens.sort_by(|a, b| a.cmp_by_position(b).unwrap());
+    /// assert_eq!(vec!["b", "a", "c"], tokens.iter().map(Text::as_str).collect::<Vec<_>>());
+    /// ```
+    pub fn cmp_by_position(&self, other: &Text) -> Option<Ordering> {
+        let (_, start, len) = self.raw_parts();
+        let (_, other_start, other_len) = other.raw_parts();
+        if self.same_buffer(other) {
+            Some((start, len).cmp(&(other_start, other_len)))
+        } else {
+            None
+        }
+    }
+
+    /// Is `other` a slice of the same backing buffer? This compares buffer identity, not data pointers,
+    /// as buffers of different owners may share their data.
+    fn same_buffer(&self, other: &Text) -> bool {
+        self.0.string.ptr_eq(&other.0.string)
+    }
+
+    fn shared_offset_of(&self, other: &Text) -> Option<usize> {
+        let (_, start, len) = self.raw_parts();
+        let (_, other_start, other_len) = other.raw_parts();
+        let contained = self.same_buffer(other)
+            && other_start >= start
+            && other_start + other_len <= start + len;
+        if contained {
+            Some(other_start - start)
+        } else {
+            None
+        }
+    }
+
+    /// Copies the [`Text`] into a new buffer of its own, releasing its hold on the original backing buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let token = Text::new("a very long document").slice(2, 6).detach();
+    /// assert_eq!("very", token.as_str());
+    /// assert_eq!(4, token.backing_len());
+    /// ```
+    pub fn detach(&self) -> Text {
+        Text::new(self.as_str())
+    }
+
+    /// Converts the [`Text`] into a `String`. This does not copy if the [`Text`] spans its whole backing buffer,
+    /// is its only handle and was created by [`Text::from_owner`] from a `String` or `Box<str>`.
+    /// In all other cases, including [`Text`]s created with [`Text::new`], which store their bytes inline, the contents are copied.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let owned = String::from("reused");
+    /// let ptr = owned.as_ptr();
+    /// assert_eq!(ptr, Text::from_owner(owned).into_string().as_ptr());
+    /// assert_eq!("copied", Text::new("copied").into_string());
+    /// ```
+    pub fn into_string(self) -> String {
+        if !self.is_root() {
+            return String::from(self.as_str());
+        }
+        match self.0.string.try_unwrap_owner::<String>() {
+            Ok(owned) => owned,
+            Err(string) => match string.try_unwrap_owner::<Box<str>>() {
+                Ok(owned) => owned.into_string(),
+                Err(string) => String::from(string.as_str()),
+            },
+        }
+    }
+
+    /// Makes sure that the [`Text`] is the only handle to a whole buffer owned by a `String`, copying it into a new one if necessary.
+    /// Afterwards, [`Text::into_string`] does not copy, which allows editing the contents in place and turning them back
+    /// into a [`Text`] with [`Text::from_owner`] without reallocating the string data. Returns whether a copy was made.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let mut text = Text::new("draft");
+    /// assert!(text.make_unique());
+    /// assert!(!text.make_unique());
+    /// let ptr = text.as_str().as_ptr();
+    /// let mut editable = text.into_string();
+    /// editable.make_ascii_uppercase();
+    /// let text = Text::from_owner(editable);
+    /// assert_eq!("DRAFT", text.as_str());
+    /// assert_eq!(ptr, text.as_str().as_ptr());
+    /// ```
+    pub fn make_unique(&mut self) -> bool {
+        if self.is_root() && self.0.string.is_unique_owner::<String>() {
+            false
+        } else {
+            *self = Text::from_owner(String::from(self.as_str()));
+            true
+        }
+    }
+
+    /// Converts the [`Text`] into a `Box<str>`, without copying under the same conditions as [`Text::into_string`].
+    /// Like `String::into_boxed_str`, a `String` owner may still be reallocated to drop excess capacity.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let owned: Box<str> = Box::from("reused");
+    /// let ptr = owned.as_ptr();
+    /// assert_eq!(ptr, Text::from_owner(owned).into_boxed_str().as_ptr());
+    /// ```
+    pub fn into_boxed_str(self) -> Box<str> {
+        if !self.is_root() {
+            return Box::from(self.as_str());
+        }
+        match self.0.string.try_unwrap_owner::<Box<str>>() {
+            Ok(owned) => owned,
+            Err(string) => match string.try_unwrap_owner::<String>() {
+                Ok(owned) => owned.into_boxed_str(),
+                Err(string) => Box::from(string.as_str()),
+            },
+        }
+    }
+
+    /// Clones the [`Text`], but detaches it (see [`Text::detach`]) if the backing buffer
+    /// is more than `max_ratio` times as long as the [`Text`] itself.
+    /// This prevents caches of small slices from keeping large buffers alive.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let doc = Text::new("0123456789");
+    /// assert_eq!(10, doc.slice(0, 5).compact(2).backing_len());
+    /// assert_eq!(2, doc.slice(0, 2).compact(2).backing_len());
+    /// ```
+    pub fn compact(&self, max_ratio: usize) -> Text {
+        if self.backing_len() > self.len().saturating_mul(max_ratio) {
+            self.detach()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Like [`Text::substring`], but detaches the result if it would otherwise keep a backing buffer alive
+    /// that is more than `max_ratio` times as long as the substring, see [`Text::compact`].
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let doc = Text::new("0123456789");
+    /// let token = doc.substring_compact(3, 2, 4);
+    /// assert_eq!("34", token.as_str());
+    /// assert_eq!(2, token.backing_len());
+    /// ```
+    pub fn substring_compact(&self, start: usize, len: usize, max_ratio: usize) -> Text {
+        self.substring(start, len).compact(max_ratio)
     }
 
     /// Gets the length of the [`Text`].
@@ -282,7 +1026,7 @@ impl Text {
     /// use quetta::Text;
     ///
     /// let text = Text::new("  a  ");
-    /// let trimmed = text.try_lift(|t| t.trim())?;
+    /// let trimmed = text.try_lift(|t| t.trim()).unwrap();
     /// assert_eq!("a", trimmed.as_str());
     /// ```
     pub fn try_lift<F: Fn(&str) -> &str>(&self, f: F) -> Option<Text> {
@@ -299,7 +1043,7 @@ impl Text {
     /// use quetta::Text;
     ///
     /// let text = Text::new("  a  ");
-    /// let trimmed = text.try_lift(|t| t.trim())?;
+    /// let trimmed = text.lift(|t| t.trim());
     /// assert_eq!("a", trimmed.as_str());
     /// ```
     pub fn lift<F: Fn(&str) -> &str>(&self, f: F) -> Text {
@@ -310,6 +1054,7 @@ impl Text {
 
     /// Lifts a function `&str -> Iterator<Item=&str>` so it will be executed on `self` and returns an `Iterator<Item=[`Text`]>`.
     /// If one of the `&str` in the iterator is not contained in `self`, the iterator will end.
+    /// Use [`Text::lift_many_strict`] to find out whether and where that happened.
     ///
     /// # Example
     /// ```
@@ -331,6 +1076,57 @@ impl Text {
         res.scan((), move |(), s| self.try_lift_slice(s)).fuse()
     }
 
+    /// Like [`Text::try_lift_many`], but returns an error identifying the first `&str` that is not contained in `self`
+    /// instead of silently ending early.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    /// let t = Text::new("a,b");
+    /// assert_eq!(2, t.lift_many_strict(|s| s.split(',')).unwrap().len());
+    /// let error = t.lift_many_strict(|s| vec![&s[..1], "b"].into_iter()).unwrap_err();
+    /// assert_eq!(1, error.index());
+    /// assert_eq!("b", error.item());
+    /// ```
+    pub fn lift_many_strict<'a, I: Iterator<Item = &'a str>, F: Fn(&'a str) -> I>(
+        &'a self,
+        f: F,
+    ) -> Result<Vec<Text>, LiftError> {
+        f(self.as_str())
+            .enumerate()
+            .map(|(index, piece)| {
+                self.try_lift_slice(piece).ok_or_else(|| LiftError {
+                    index,
+                    item: String::from(piece),
+                })
+            })
+            .collect()
+    }
+
+    /// Like [`Text::try_lift_many`], but also yields the byte offset of every piece within `self`,
+    /// which is known while lifting anyway. Useful for building span tables while splitting.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    /// let t = Text::new("let x = 1");
+    /// let tokens: Vec<(usize, Text)> = t.try_lift_many_indexed(|s| s.split_whitespace()).collect();
+    /// assert_eq!(4, tokens.len());
+    /// assert_eq!(6, tokens[2].0);
+    /// assert_eq!("=", tokens[2].1.as_str());
+    /// ```
+    pub fn try_lift_many_indexed<'a, I: Iterator<Item = &'a str> + 'a, F: Fn(&'a str) -> I>(
+        &'a self,
+        f: F,
+    ) -> impl Iterator<Item = (usize, Text)> + 'a {
+        let s = self.as_str();
+        let res = f(s);
+        res.map_while(move |piece| {
+            get_offset(s, piece).map(|offset| (offset, self.substring(offset, piece.len())))
+        })
+        .fuse()
+    }
+
     /// Lifts a function `&str -> Iterator<Item=&str>` so it will be executed on `self` and returns an `Iterator<Item=[`Text`]>`.
     /// If the iterator yields a `&str` that is not contained within `self`, a new [`Text`] will be created from it.
     ///
@@ -353,6 +1149,47 @@ impl Text {
         let res = f(s);
         res.map(move |s| self.try_lift_slice(s).unwrap_or_else(|| Text::new(s)))
     }
+
+    /// Splits the [`Text`] on a single ASCII character, like `str::split_terminator`,
+    /// yielding slices of `self`. The search is done with `memchr`, which makes this considerably faster
+    /// than the generic pattern-based splitting.
+    /// Will panic if `delimiter` is not an ASCII character.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let t = Text::new("a\tb\t\tc\t");
+    /// let fields: Vec<Text> = t.split_terminator_char('\t').collect();
+    /// assert_eq!(4, fields.len());
+    /// assert_eq!("b", fields[1].as_str());
+    /// assert_eq!("", fields[2].as_str());
+    /// assert_eq!("c", fields[3].as_str());
+    /// ```
+    pub fn split_terminator_char(&self, delimiter: char) -> impl Iterator<Item = Text> + '_ {
+        if !delimiter.is_ascii() {
+            panic!(
+                "Delimiter must be an ASCII character, but was {:?}",
+                delimiter
+            )
+        }
+        let len = self.len();
+        let mut positions = memchr::memchr_iter(delimiter as u8, self.as_str().as_bytes());
+        let mut start = 0;
+        std::iter::from_fn(move || match positions.next() {
+            Some(pos) => {
+                let piece = self.slice(start, pos);
+                start = pos + 1;
+                Some(piece)
+            }
+            None if start < len => {
+                let piece = self.slice(start, len);
+                start = len;
+                Some(piece)
+            }
+            None => None,
+        })
+    }
 }
 
 fn get_offset(original: &str, slice: &str) -> Option<usize> {
@@ -369,7 +1206,8 @@ fn get_offset(original: &str, slice: &str) -> Option<usize> {
 
 #[cfg(test)]
 mod tests {
-    use crate::Text;
+    use crate::{IString, SliceError, Text, TextData, TooLargeError};
+    use std::cmp::Ordering;
 
     #[test]
     pub fn test_slice1() {
@@ -385,6 +1223,17 @@ mod tests {
         assert_eq!(0, s2.len())
     }
 
+    #[test]
+    pub fn test_format() {
+        let t = Text::new("xGrüßex").slice(1, 8);
+        assert_eq!("Grüße", format!("{}", t));
+        assert_eq!("     Grüße", format!("{:>10}", t));
+        assert_eq!("Grü", format!("{:.3}", t));
+        assert_eq!("-Grü-", format!("{:-^5.3}", t));
+        assert_eq!("Grüße  ", format!("{:7?}", t));
+        assert_eq!("  Gr", format!("{:>4.2}", t.as_text_ref()));
+    }
+
     #[test]
     #[should_panic]
     pub fn test_invalid_slices1() {
@@ -399,6 +1248,270 @@ mod tests {
         t.slice(6, 8);
     }
 
+    #[test]
+    #[should_panic(expected = "not on a char boundary")]
+    pub fn test_invalid_slices3() {
+        let t = Text::new("añb");
+        t.slice(2, 4);
+    }
+
+    #[test]
+    pub fn test_try_slice() {
+        let t = Text::new("añb");
+        assert_eq!("ñ", t.try_slice(1, 3).unwrap().as_str());
+        assert_eq!(
+            Err(SliceError::NotCharBoundary { index: 2 }),
+            t.try_slice(2, 4)
+        );
+        assert_eq!(
+            Err(SliceError::InvertedRange { start: 3, end: 1 }),
+            t.try_slice(3, 1)
+        );
+        assert_eq!(
+            Err(SliceError::OutOfBounds {
+                text_len: 4,
+                start: 1,
+                len: usize::MAX
+            }),
+            t.try_substring(1, usize::MAX)
+        );
+        let sub = t.slice(1, 4);
+        assert_eq!(
+            Err(SliceError::NotCharBoundary { index: 1 }),
+            sub.try_slice(1, 3)
+        );
+    }
+
+    #[test]
+    pub fn test_as_str_checked() {
+        let root = IString::new("añb");
+        let broken = Text(TextData {
+            string: root.clone(),
+            start: 2,
+            len: 2,
+        });
+        assert_eq!(
+            Err(SliceError::NotCharBoundary { index: 2 }),
+            broken.as_str_checked()
+        );
+        assert_eq!("\u{FFFD}b", broken.as_str_lossy());
+        let overlong = Text(TextData {
+            string: root,
+            start: 3,
+            len: 5,
+        });
+        assert_eq!(
+            Err(SliceError::OutOfBounds {
+                text_len: 4,
+                start: 3,
+                len: 5
+            }),
+            overlong.as_str_checked()
+        );
+        assert_eq!("b", overlong.as_str_lossy());
+    }
+
+    #[test]
+    pub fn test_offsets() {
+        let t = Text::new("abcdef");
+        assert_eq!("cd", t.slice(1, 5).slice(1, 3).as_str());
+        let error = TooLargeError { len: usize::MAX };
+        assert_eq!(usize::MAX, error.string_len());
+        assert!(error.to_string().contains("exceeds the maximum length"));
+        #[cfg(feature = "u32-offsets")]
+        assert_eq!(
+            std::mem::size_of::<Text>(),
+            2 * std::mem::size_of::<usize>()
+        );
+        #[cfg(not(feature = "u32-offsets"))]
+        assert_eq!(
+            std::mem::size_of::<Text>(),
+            3 * std::mem::size_of::<usize>()
+        );
+    }
+
+    #[test]
+    pub fn test_option_niche() {
+        use std::mem::size_of;
+        assert_eq!(size_of::<Text>(), size_of::<Option<Text>>());
+        assert_eq!(
+            size_of::<(Text, Text)>(),
+            size_of::<Option<(Text, Option<Text>)>>()
+        );
+        let maybe: Option<Text> = Some(Text::new("a"));
+        assert_eq!(Some("a"), maybe.as_ref().map(Text::as_str));
+    }
+
+    #[test]
+    pub fn test_from_owner() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct Owner(String, Arc<AtomicUsize>);
+        impl AsRef<str> for Owner {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+        impl Drop for Owner {
+            fn drop(&mut self) {
+                self.1.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let drops = Arc::new(AtomicUsize::new(0));
+        let text = Text::from_owner(Owner(String::from("añb"), drops.clone()));
+        let slice = text.slice(1, 4);
+        drop(text);
+        assert_eq!(0, drops.load(Ordering::SeqCst));
+        assert_eq!("ñb", slice.as_str());
+        assert_eq!(4, slice.backing_len());
+        drop(slice);
+        assert_eq!(1, drops.load(Ordering::SeqCst));
+        assert_eq!("static", Text::from_owner("static").as_str());
+    }
+
+    #[test]
+    pub fn test_into_string() {
+        let owned = String::from("añb");
+        let ptr = owned.as_ptr();
+        let text = Text::from_owner(owned);
+        let shared = text.clone();
+        let copied = text.into_string();
+        assert_ne!(ptr, copied.as_ptr());
+        let boxed = shared.into_boxed_str();
+        assert_eq!(ptr, boxed.as_ptr());
+        let text = Text::from_owner(boxed);
+        assert_eq!("ñ", text.slice(1, 3).into_string());
+        let string = String::from(text);
+        assert_eq!(ptr, string.as_ptr());
+        assert_eq!("añb", &*Text::new("añb").into_boxed_str());
+    }
+
+    #[test]
+    pub fn test_make_unique() {
+        let root = Text::from_owner(String::from("añb"));
+        let mut slice = root.slice(1, 4);
+        assert!(slice.make_unique());
+        assert_eq!("ñb", slice.as_str());
+        let mut shared = root.clone();
+        assert!(shared.make_unique());
+        assert_ne!(root.as_str().as_ptr(), shared.as_str().as_ptr());
+        let mut unique = root;
+        let ptr = unique.as_str().as_ptr();
+        assert!(!unique.make_unique());
+        assert_eq!(ptr, unique.as_str().as_ptr());
+    }
+
+    #[test]
+    pub fn test_nested_slices() {
+        let s = "aä€𝄞b";
+        let root = Text::new(s);
+        let boundaries: Vec<usize> = (0..=s.len()).filter(|i| s.is_char_boundary(*i)).collect();
+        for (i, start) in boundaries.iter().enumerate() {
+            for end in &boundaries[i..] {
+                let outer = root.slice(*start, *end);
+                assert_eq!(&s[*start..*end], outer.as_str());
+                assert_eq!(*start, outer.offset_in_parent());
+                assert_eq!(outer.is_root(), *start == 0 && *end == s.len());
+                let inner_str = outer.as_str();
+                for (j, inner_start) in boundaries.iter().enumerate() {
+                    for inner_end in &boundaries[j..] {
+                        let result = outer.try_slice(*inner_start, *inner_end);
+                        match inner_str.get(*inner_start..*inner_end) {
+                            Some(expected) => {
+                                let inner = result.unwrap();
+                                assert_eq!(expected, inner.as_str());
+                                assert_eq!(start + inner_start, inner.offset_in_parent());
+                                assert_eq!(root, inner.parent());
+                            }
+                            None => assert!(result.is_err()),
+                        }
+                    }
+                }
+            }
+        }
+        assert!(root.parent().is_root());
+        assert_eq!(root.as_str().as_ptr(), root.parent().as_str().as_ptr());
+    }
+
+    #[test]
+    pub fn test_byte_offset_of() {
+        let root = Text::new("abcabc");
+        let outer = root.slice(1, 6);
+        let inner = root.slice(3, 5);
+        assert!(outer.contains_slice(&inner));
+        assert_eq!(Some(2), outer.byte_offset_of(&inner));
+        assert!(!inner.contains_slice(&outer));
+        assert_eq!(None, inner.byte_offset_of(&outer));
+        assert!(outer.contains_slice(&root.slice(6, 6)));
+        assert!(!root.slice(0, 3).contains_slice(&inner));
+        assert_eq!(Some(0), root.slice(0, 3).byte_offset_of(&inner));
+        let copy = Text::new("abcabc");
+        assert!(!copy.contains_slice(&inner));
+        assert_eq!(Some(0), copy.byte_offset_of(&inner));
+        let shared: &'static str = "abcdef";
+        let (short, long) = (Text::from_owner(&shared[..3]), Text::from_owner(shared));
+        assert!(!short.contains_slice(&long.slice(0, 2)));
+        assert!(!long.contains_slice(&short));
+        assert_eq!(Some(0), long.byte_offset_of(&short));
+    }
+
+    #[test]
+    pub fn test_cmp_by_position() {
+        let root = Text::new("abcd");
+        let (a, b) = (root.slice(1, 2), root.slice(1, 3));
+        assert_eq!(Some(Ordering::Less), a.cmp_by_position(&b));
+        assert_eq!(
+            Some(Ordering::Greater),
+            root.slice(2, 2).cmp_by_position(&b)
+        );
+        assert_eq!(Some(Ordering::Equal), a.cmp_by_position(&a.clone()));
+        assert_eq!(None, a.cmp_by_position(&Text::new("b")));
+        let shared: &'static str = "abcdef";
+        let (short, long) = (Text::from_owner(&shared[..3]), Text::from_owner(shared));
+        assert_eq!(None, short.cmp_by_position(&long));
+    }
+
+    #[test]
+    pub fn test_checked_concat_adjacent() {
+        let root = Text::new("abcd");
+        let joined = Text::checked_concat_adjacent(&root.slice(1, 2), &root.slice(2, 4)).unwrap();
+        assert_eq!("bcd", joined.as_str());
+        assert_eq!(root.as_str()[1..].as_ptr(), joined.as_str().as_ptr());
+        let empty = root.slice(2, 2);
+        assert!(Text::checked_concat_adjacent(&root.slice(0, 2), &empty).is_some());
+        assert!(Text::checked_concat_adjacent(&root.slice(0, 1), &root.slice(2, 3)).is_none());
+        let copy = Text::new("abcd");
+        assert!(Text::checked_concat_adjacent(&root.slice(0, 2), &copy.slice(2, 4)).is_none());
+        let shared: &'static str = "abcdef";
+        let (short, long) = (Text::from_owner(&shared[..3]), Text::from_owner(shared));
+        assert!(Text::checked_concat_adjacent(&short, &long.slice(3, 6)).is_none());
+    }
+
+    #[test]
+    pub fn test_substring_unchecked() {
+        let t = Text::new("a.b.c");
+        let s1 = t.slice(2, 5);
+        let s2 = unsafe { s1.substring_unchecked(1, 2) };
+        assert_eq!(s1.substring(1, 2), s2);
+        assert_eq!(".c", s2.as_str());
+    }
+
+    #[test]
+    pub fn test_compact() {
+        let doc = Text::new("abcdefghij");
+        let small = doc.slice(1, 3);
+        assert_eq!(10, small.backing_len());
+        let compacted = small.compact(4);
+        assert_eq!(small, compacted);
+        assert_eq!(2, compacted.backing_len());
+        let large = doc.slice(0, 8);
+        assert_eq!(large.as_str().as_ptr(), large.compact(2).as_str().as_ptr());
+        assert_eq!(0, doc.slice(3, 3).compact(usize::MAX).backing_len());
+        assert_eq!(10, doc.compact(1).backing_len());
+    }
+
     #[test]
     pub fn test_lift() {
         let t = Text::new(" TEST  ");
@@ -416,4 +1529,45 @@ mod tests {
         assert_eq!("C", lifted[2].as_str());
         assert_eq!("D", lifted[3].as_str());
     }
+
+    #[test]
+    pub fn test_lift_many_strict() {
+        let t = Text::new("a b c");
+        let lifted = t.lift_many_strict(|s| s.split(' ')).unwrap();
+        assert_eq!(
+            vec!["a", "b", "c"],
+            lifted.iter().map(Text::as_str).collect::<Vec<_>>()
+        );
+        let error = t
+            .lift_many_strict(|s| s.split(' ').map(|p| if p == "c" { "ç" } else { p }))
+            .unwrap_err();
+        assert_eq!(2, error.index());
+        assert_eq!("ç", error.item());
+        assert!(error.to_string().contains("Item 2"));
+    }
+
+    #[test]
+    pub fn test_try_lift_many_indexed() {
+        let t = Text::new("(a, bñ, c)").slice(1, 10);
+        let pieces: Vec<(usize, Text)> = t.try_lift_many_indexed(|s| s.split(", ")).collect();
+        let pieces: Vec<(usize, &str)> = pieces.iter().map(|(i, p)| (*i, p.as_str())).collect();
+        assert_eq!(vec![(0, "a"), (3, "bñ"), (8, "c")], pieces);
+        let foreign = t.try_lift_many_indexed(|s| vec![&s[..1], "x", &s[3..4]].into_iter());
+        assert_eq!(1, foreign.count());
+    }
+
+    #[test]
+    pub fn test_split_terminator_char() {
+        let t = Text::new("a,b,,c,");
+        let expected: Vec<&str> = t.as_str().split_terminator(',').collect();
+        let split: Vec<Text> = t.split_terminator_char(',').collect();
+        assert_eq!(expected, split.iter().map(Text::as_str).collect::<Vec<_>>());
+        assert_eq!(0, Text::new("").split_terminator_char(',').count());
+        let sub = t.slice(2, 6);
+        let split: Vec<Text> = sub.split_terminator_char(',').collect();
+        assert_eq!(
+            vec!["b", "", "c"],
+            split.iter().map(Text::as_str).collect::<Vec<_>>()
+        );
+    }
 }
@@ -0,0 +1,396 @@
+use crate::{get_offset, Text};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+/// Strips a trailing `\r` from a line, like `str::lines` does.
+fn strip_cr(line: &[u8]) -> &[u8] {
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+/// A precomputed index of the lines of a [`Text`] (as `str::lines` splits them),
+/// giving O(1) access to any line and O(log n) lookup of the line containing an offset.
+///
+/// # Example
+/// ```
+/// use quetta::Text;
+///
+/// let table = Text::new("first\nsecond\r\nthird").random_access_lines();
+/// assert_eq!(3, table.line_count());
+/// assert_eq!("second", table.line(1).unwrap().as_str());
+/// assert_eq!(Some(2), table.line_of_offset(15));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineTable {
+    text: Text,
+    /// Start and end (excluding the line terminator) of every line.
+    lines: Vec<(usize, usize)>,
+}
+
+impl LineTable {
+    /// Indexes the lines of `text`.
+    pub fn new(text: Text) -> Self {
+        let s = text.as_str();
+        let lines = s
+            .lines()
+            .filter_map(|line| get_offset(s, line).map(|start| (start, start + line.len())))
+            .collect();
+        Self { text, lines }
+    }
+
+    /// Gets the indexed [`Text`].
+    pub fn text(&self) -> &Text {
+        &self.text
+    }
+
+    /// Gets the number of lines.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Gets the `n`th line (starting at 0), without its line terminator.
+    pub fn line(&self, n: usize) -> Option<Text> {
+        self.lines
+            .get(n)
+            .map(|(start, end)| self.text.slice(*start, *end))
+    }
+
+    /// Gets the byte offset at which the `n`th line starts.
+    pub fn line_start(&self, n: usize) -> Option<usize> {
+        self.lines.get(n).map(|(start, _)| *start)
+    }
+
+    /// Gets the index of the line containing the byte `offset`. Offsets within a line terminator belong to the line it ends.
+    /// Returns `None` if `offset` is beyond the end of the [`Text`] or there are no lines.
+    pub fn line_of_offset(&self, offset: usize) -> Option<usize> {
+        if offset > self.text.len() {
+            return None;
+        }
+        self.lines
+            .partition_point(|(start, _)| *start <= offset)
+            .checked_sub(1)
+    }
+}
+
+impl Text {
+    /// Creates a [`LineTable`] for random access to the lines of the [`Text`].
+    pub fn random_access_lines(&self) -> LineTable {
+        LineTable::new(self.clone())
+    }
+
+    /// Gets the full line(s) containing `slice`, which must be a slice of the [`Text`], without their final line terminator,
+    /// together with the range of (0-based) line numbers they span. Useful for showing context in diagnostics.
+    /// Returns `None` if `slice` is not part of the [`Text`].
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let source = Text::new("fn main() {\n    let x = 1;\n}\n");
+    /// let token = source.slice(20, 21);
+    /// let (line, numbers) = source.enclosing_line(token.as_str()).unwrap();
+    /// assert_eq!("    let x = 1;", line.as_str());
+    /// assert_eq!(1..2, numbers);
+    /// ```
+    pub fn enclosing_line(&self, slice: &str) -> Option<(Text, Range<usize>)> {
+        let bytes = self.as_str().as_bytes();
+        let start = get_offset(self.as_str(), slice)?;
+        let end = start + slice.len();
+        // A terminator at the very end of the slice belongs to the last line it covers.
+        let last = if end > start && bytes[end - 1] == b'\n' {
+            end - 1
+        } else {
+            end
+        };
+        let line_start = memchr::memrchr(b'\n', &bytes[..start]).map_or(0, |i| i + 1);
+        let line_end = memchr::memchr(b'\n', &bytes[last..]).map_or(bytes.len(), |i| last + i);
+        let line_end = line_start + strip_cr(&bytes[line_start..line_end]).len();
+        let first_line = memchr::memchr_iter(b'\n', &bytes[..line_start]).count();
+        let last_line = first_line + memchr::memchr_iter(b'\n', &bytes[line_start..last]).count();
+        Some((self.slice(line_start, line_end), first_line..last_line + 1))
+    }
+
+    /// Binary searches the lines of a [`Text`] whose lines are sorted, without splitting it first.
+    /// Returns the byte offset of the matching line and the line itself (without line terminator),
+    /// or `None` if no line equals `key`.
+    /// The result is unspecified if the lines are not sorted.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let dictionary = Text::new("apple\nbanana\ncherry\n");
+    /// let (offset, line) = dictionary.lines_binary_search("banana").unwrap();
+    /// assert_eq!(6, offset);
+    /// assert_eq!("banana", line.as_str());
+    /// assert!(dictionary.lines_binary_search("durian").is_none());
+    /// ```
+    pub fn lines_binary_search(&self, key: &str) -> Option<(usize, Text)> {
+        let bytes = self.as_str().as_bytes();
+        let mut low = 0;
+        let mut high = bytes.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let start = memchr::memrchr(b'\n', &bytes[low..mid]).map_or(low, |i| low + i + 1);
+            let end = memchr::memchr(b'\n', &bytes[mid..]).map_or(bytes.len(), |i| mid + i);
+            let line = strip_cr(&bytes[start..end]);
+            match line.cmp(key.as_bytes()) {
+                Ordering::Equal => return Some((start, self.substring(start, line.len()))),
+                Ordering::Less => low = end + 1,
+                Ordering::Greater => high = start,
+            }
+        }
+        None
+    }
+
+    /// Splits the [`Text`] into a header and a body at the first line equal to `separator_line` (ignoring its line terminator),
+    /// e.g. `""` for the blank line ending mail headers or `"---"` for front matter. If the [`Text`] starts with a non-empty
+    /// separator line, as front matter does, that opening line is skipped. The header keeps the terminator of its last line,
+    /// while the separator line is part of neither half. Returns `None` if there is no separator line.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let mail = Text::new("Subject: Hi\r\nFrom: me\r\n\r\nHello!");
+    /// let (header, body) = mail.split_header_body("").unwrap();
+    /// assert_eq!("Subject: Hi\r\nFrom: me\r\n", header.as_str());
+    /// assert_eq!("Hello!", body.as_str());
+    ///
+    /// let page = Text::new("---\ntitle: Home\n---\n# Welcome\n");
+    /// let (front_matter, content) = page.split_header_body("---").unwrap();
+    /// assert_eq!("title: Home\n", front_matter.as_str());
+    /// assert_eq!("# Welcome\n", content.as_str());
+    /// ```
+    pub fn split_header_body(&self, separator_line: &str) -> Option<(Text, Text)> {
+        let bytes = self.as_str().as_bytes();
+        let separator = separator_line.as_bytes();
+        let mut header_start = None;
+        let mut pos = 0;
+        while pos < bytes.len() {
+            let end = memchr::memchr(b'\n', &bytes[pos..]).map_or(bytes.len(), |i| pos + i);
+            let next = (end + 1).min(bytes.len());
+            if strip_cr(&bytes[pos..end]) == separator {
+                match header_start {
+                    None if pos == 0 && !separator.is_empty() => header_start = Some(next),
+                    start => {
+                        let start = start.unwrap_or(0);
+                        return Some((self.slice(start, pos), self.slice(next, bytes.len())));
+                    }
+                }
+            } else if header_start.is_none() {
+                header_start = Some(0);
+            }
+            pos = next;
+        }
+        None
+    }
+
+    /// Iterates over the lines of two texts (as `str::lines` splits them) side by side, as slices of `self` and `other`.
+    /// Once one text runs out of lines, its side is `None`, until both have run out.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let expected = Text::new("a\nb\nc\n");
+    /// let actual = Text::new("a\nB");
+    /// let mismatches: Vec<usize> = expected
+    ///     .zip_lines(&actual)
+    ///     .enumerate()
+    ///     .filter(|(_, (e, a))| e != a)
+    ///     .map(|(n, _)| n)
+    ///     .collect();
+    /// assert_eq!(vec![1, 2], mismatches);
+    /// ```
+    pub fn zip_lines<'a>(
+        &'a self,
+        other: &'a Text,
+    ) -> impl Iterator<Item = (Option<Text>, Option<Text>)> + 'a {
+        let mut left = self.as_str().lines().map(move |line| self.lift_slice(line));
+        let mut right = other
+            .as_str()
+            .lines()
+            .map(move |line| other.lift_slice(line));
+        std::iter::from_fn(move || match (left.next(), right.next()) {
+            (None, None) => None,
+            pair => Some(pair),
+        })
+    }
+
+    /// Gets the lines of the [`Text`] (as `str::lines` splits them), keeping only the first occurrence of each line.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let log = Text::new("a\nb\na\nc\nb");
+    /// let unique: Vec<String> = log.unique_lines().iter().map(|l| l.to_string()).collect();
+    /// assert_eq!(vec!["a", "b", "c"], unique);
+    /// ```
+    pub fn unique_lines(&self) -> Vec<Text> {
+        let mut seen = HashSet::new();
+        self.as_str()
+            .lines()
+            .filter(|line| seen.insert(*line))
+            .map(|line| self.lift_slice(line))
+            .collect()
+    }
+
+    /// Gets the lines of the [`Text`] that occur more than once, each reported once, in order of their first occurrence.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let log = Text::new("a\nb\na\nc\nb\na");
+    /// let duplicates: Vec<String> = log.duplicate_lines().iter().map(|l| l.to_string()).collect();
+    /// assert_eq!(vec!["a", "b"], duplicates);
+    /// ```
+    pub fn duplicate_lines(&self) -> Vec<Text> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for line in self.as_str().lines() {
+            *counts.entry(line).or_insert(0) += 1;
+        }
+        let mut reported = HashSet::new();
+        self.as_str()
+            .lines()
+            .filter(|line| counts[line] > 1 && reported.insert(*line))
+            .map(|line| self.lift_slice(line))
+            .collect()
+    }
+
+    /// Sorts the lines of the [`Text`], joining them with `\n`. A trailing newline is kept.
+    /// If the lines are already sorted, `self` is returned without copying.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let names = Text::new("carol\nalice\nbob\n");
+    /// assert_eq!("alice\nbob\ncarol\n", names.sort_lines().as_str());
+    /// ```
+    pub fn sort_lines(&self) -> Text {
+        let mut lines: Vec<&str> = self.as_str().lines().collect();
+        if lines.windows(2).all(|w| w[0] <= w[1]) {
+            return self.clone();
+        }
+        lines.sort_unstable();
+        let mut sorted = String::with_capacity(self.len());
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                sorted.push('\n');
+            }
+            sorted.push_str(line);
+        }
+        if self.as_str().ends_with('\n') {
+            sorted.push('\n');
+        }
+        Text::new(sorted.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_lines_binary_search() {
+        let words = ["", "a", "ab", "abc", "b", "ä", "ö", "ü"];
+        let t = Text::new(words.join("\r\n").as_str());
+        let mut offset = 0;
+        for w in words.iter() {
+            assert_eq!(Some((offset, Text::new(*w))), t.lines_binary_search(w));
+            offset += w.len() + 2;
+        }
+        assert_eq!(None, t.lines_binary_search("aa"));
+        assert_eq!(None, t.lines_binary_search("zz"));
+        assert_eq!(None, Text::default().lines_binary_search(""));
+    }
+
+    #[test]
+    pub fn test_split_header_body() {
+        let split = |s: &str, separator: &str| {
+            Text::new(s)
+                .split_header_body(separator)
+                .map(|(h, b)| (h.to_string(), b.to_string()))
+        };
+        let pair = |h: &str, b: &str| Some((String::from(h), String::from(b)));
+        assert_eq!(pair("", "body"), split("\nbody", ""));
+        assert_eq!(pair("a\n", ""), split("a\n\n", ""));
+        assert_eq!(pair("a\n", ""), split("a\n\r", ""));
+        assert_eq!(pair("", "b"), split("--\n--\nb", "--"));
+        assert_eq!(pair("a\n", "b"), split("a\n--\nb", "--"));
+        assert_eq!(None, split("--\na", "--"));
+        assert_eq!(None, split("a\nb", ""));
+        assert_eq!(None, split("", ""));
+    }
+
+    #[test]
+    pub fn test_zip_lines() {
+        let a = Text::new("x\r\n\ny");
+        let b = Text::new("x");
+        let pairs: Vec<(Option<Text>, Option<Text>)> = a.zip_lines(&b).collect();
+        assert_eq!(3, pairs.len());
+        assert_eq!((Some(Text::new("x")), Some(Text::new("x"))), pairs[0]);
+        assert_eq!((Some(Text::new("")), None), pairs[1]);
+        assert_eq!(Some(Text::new("y")), pairs[2].0);
+        assert_eq!(
+            a.as_str()[4..].as_ptr(),
+            pairs[2].0.as_ref().unwrap().as_str().as_ptr()
+        );
+        assert_eq!(0, Text::default().zip_lines(&Text::default()).count());
+    }
+
+    #[test]
+    pub fn test_line_utilities() {
+        let t = Text::new("b\r\na\nb\n\n\na");
+        let as_strs = |lines: Vec<Text>| lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+        assert_eq!(vec!["b", "a", ""], as_strs(t.unique_lines()));
+        assert_eq!(vec!["b", "a", ""], as_strs(t.duplicate_lines()));
+        assert_eq!("\n\na\na\nb\nb", t.sort_lines().as_str());
+        let sorted = Text::new("a\nb\n");
+        assert_eq!(
+            sorted.as_str().as_ptr(),
+            sorted.sort_lines().as_str().as_ptr()
+        );
+        let unique = t.unique_lines();
+        assert!(t.try_lift_slice(unique[1].as_str()).is_some());
+    }
+
+    #[test]
+    pub fn test_enclosing_line() {
+        let t = Text::new("zero\r\none\ntwo\n\nfour");
+        let enclosing = |start: usize, end: usize| {
+            let (lines, numbers) = t.enclosing_line(&t.as_str()[start..end]).unwrap();
+            (lines.to_string(), numbers)
+        };
+        assert_eq!((String::from("zero"), 0..1), enclosing(1, 3));
+        assert_eq!((String::from("zero"), 0..1), enclosing(0, 6));
+        assert_eq!((String::from("zero\r\none"), 0..2), enclosing(2, 7));
+        assert_eq!((String::from("one\ntwo"), 1..3), enclosing(8, 11));
+        assert_eq!((String::from(""), 3..4), enclosing(14, 14));
+        assert_eq!((String::from("four"), 4..5), enclosing(19, 19));
+        assert_eq!(None, t.enclosing_line("one"));
+    }
+
+    #[test]
+    pub fn test_line_table() {
+        let t = Text::new("ab\n\ncd\r\ne\n");
+        let table = t.random_access_lines();
+        assert_eq!(4, table.line_count());
+        let lines: Vec<String> = (0..4).map(|n| table.line(n).unwrap().to_string()).collect();
+        assert_eq!(t.as_str().lines().collect::<Vec<_>>(), lines);
+        assert_eq!(None, table.line(4));
+        assert_eq!(Some(4), table.line_start(2));
+        let expected = [0, 0, 0, 1, 2, 2, 2, 2, 3, 3, 3];
+        for (offset, line) in expected.iter().enumerate() {
+            assert_eq!(Some(*line), table.line_of_offset(offset));
+        }
+        assert_eq!(None, table.line_of_offset(11));
+        assert_eq!(
+            None,
+            Text::default().random_access_lines().line_of_offset(0)
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Text, TextSeq};
+use std::fmt::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A log line made of a timestamp, labels and a message payload, for forwarders that relabel messages without modifying them.
+/// [`LogRecord::to_seq`] formats everything but the payload into a single prefix and keeps the payload as a separate piece,
+/// so the message body is never copied.
+///
+/// # Example
+/// ```
+/// use quetta::{LogRecord, Text};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let payload = Text::new("connection reset");
+/// let mut record = LogRecord::new(UNIX_EPOCH + Duration::from_millis(1_700_000_000_123), payload.clone());
+/// record.label("level", "warn");
+/// record.label("host", "db-1");
+/// let line = record.to_seq();
+/// assert_eq!("2023-11-14T22:13:20.123Z level=warn host=db-1 connection reset", line.to_string());
+/// assert_eq!(payload.as_str().as_ptr(), line.pieces()[1].as_str().as_ptr());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LogRecord {
+    timestamp: SystemTime,
+    labels: Vec<(Text, Text)>,
+    payload: Text,
+}
+
+impl LogRecord {
+    /// Creates a [`LogRecord`] without labels.
+    pub fn new(timestamp: SystemTime, payload: Text) -> Self {
+        Self {
+            timestamp,
+            labels: Vec::new(),
+            payload,
+        }
+    }
+
+    /// Adds a `key=value` label, which is written after the timestamp and previously added labels.
+    pub fn label<K: Into<Text>, V: Into<Text>>(&mut self, key: K, value: V) {
+        self.labels.push((key.into(), value.into()));
+    }
+
+    /// Gets the timestamp.
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    /// Gets the labels in the order they were added.
+    pub fn labels(&self) -> &[(Text, Text)] {
+        &self.labels
+    }
+
+    /// Gets the message payload.
+    pub fn payload(&self) -> &Text {
+        &self.payload
+    }
+
+    /// Formats the record as a [`TextSeq`] of two pieces: the prefix, made of the timestamp as RFC 3339 in UTC
+    /// with millisecond precision and the labels, each followed by a space, and the payload itself.
+    pub fn to_seq(&self) -> TextSeq {
+        let mut prefix = String::new();
+        write_timestamp(&mut prefix, self.timestamp);
+        prefix.push(' ');
+        for (key, value) in &self.labels {
+            let _ = write!(prefix, "{}={} ", key, value);
+        }
+        let mut seq = TextSeq::new();
+        seq.push(Text::from_owner(prefix));
+        seq.push(self.payload.clone());
+        seq
+    }
+}
+
+/// Writes `time` as `YYYY-MM-DDTHH:MM:SS.mmmZ`, using the proleptic Gregorian calendar.
+fn write_timestamp(out: &mut String, time: SystemTime) {
+    let millis = match time.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_millis() as i64,
+        Err(e) => -(e.duration().as_millis() as i64),
+    };
+    let days = millis.div_euclid(86_400_000);
+    let ms_of_day = millis.rem_euclid(86_400_000);
+    // Days to civil date, after Howard Hinnant's `civil_from_days`
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    let _ = write!(
+        out,
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        ms_of_day / 3_600_000,
+        ms_of_day / 60_000 % 60,
+        ms_of_day / 1000 % 60,
+        ms_of_day % 1000
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LogRecord, Text};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn prefix(millis: i64) -> String {
+        let time = if millis >= 0 {
+            UNIX_EPOCH + Duration::from_millis(millis as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_millis(-millis as u64)
+        };
+        LogRecord::new(time, Text::default()).to_seq().to_string()
+    }
+
+    #[test]
+    pub fn test_log_record() {
+        assert_eq!("1970-01-01T00:00:00.000Z ", prefix(0));
+        assert_eq!("1969-12-31T23:59:59.999Z ", prefix(-1));
+        assert_eq!("2000-02-29T12:34:56.789Z ", prefix(951_827_696_789));
+        assert_eq!("2100-03-01T00:00:00.000Z ", prefix(4_107_542_400_000));
+        let mut record = LogRecord::new(UNIX_EPOCH, Text::new("msg"));
+        record.label(Text::new("k"), "v");
+        assert_eq!(1, record.labels().len());
+        assert_eq!("msg", record.payload().as_str());
+        assert_eq!(UNIX_EPOCH, record.timestamp());
+        assert_eq!(2, record.to_seq().pieces().len());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::Text;
+
+/// An inline run produced by [`Text::markdown_inlines`]. All runs are slices of the original [`Text`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inline {
+    /// Plain text, which may include backslash escapes.
+    Plain(Text),
+    /// A code span. `raw` includes the backticks, `content` does not.
+    Code { raw: Text, content: Text },
+    /// A run of emphasis or strikethrough delimiters (`*`, `_` or `~`).
+    Delimiter(Text),
+}
+
+impl Inline {
+    /// Gets the slice of the original [`Text`] this run covers.
+    pub fn raw(&self) -> &Text {
+        match self {
+            Inline::Plain(t) | Inline::Delimiter(t) => t,
+            Inline::Code { raw, .. } => raw,
+        }
+    }
+}
+
+fn is_delimiter(b: u8) -> bool {
+    matches!(b, b'*' | b'_' | b'~')
+}
+
+fn run_len(bytes: &[u8], pos: usize) -> usize {
+    bytes[pos..]
+        .iter()
+        .take_while(|b| **b == bytes[pos])
+        .count()
+}
+
+/// Finds the end of the code span whose opening backtick run of length `ticks` ends at `pos`.
+fn find_code_end(bytes: &[u8], mut pos: usize, ticks: usize) -> Option<usize> {
+    while pos < bytes.len() {
+        if bytes[pos] == b'`' {
+            let len = run_len(bytes, pos);
+            if len == ticks {
+                return Some(pos);
+            }
+            pos += len;
+        } else {
+            pos += 1;
+        }
+    }
+    None
+}
+
+struct MarkdownInlines<'a> {
+    text: &'a Text,
+    pos: usize,
+}
+
+impl Iterator for MarkdownInlines<'_> {
+    type Item = Inline;
+
+    fn next(&mut self) -> Option<Inline> {
+        let bytes = self.text.as_str().as_bytes();
+        let start = self.pos;
+        if start >= bytes.len() {
+            return None;
+        }
+        if is_delimiter(bytes[start]) {
+            self.pos += run_len(bytes, start);
+            return Some(Inline::Delimiter(self.text.slice(start, self.pos)));
+        }
+        if bytes[start] == b'`' {
+            let ticks = run_len(bytes, start);
+            if let Some(end) = find_code_end(bytes, start + ticks, ticks) {
+                self.pos = end + ticks;
+                return Some(Inline::Code {
+                    raw: self.text.slice(start, self.pos),
+                    content: self.text.slice(start + ticks, end),
+                });
+            }
+            // An unmatched backtick run is plain text
+            self.pos += ticks;
+        }
+        while self.pos < bytes.len() {
+            match bytes[self.pos] {
+                b'\\'
+                    if bytes
+                        .get(self.pos + 1)
+                        .is_some_and(|b| b.is_ascii_punctuation()) =>
+                {
+                    self.pos += 2
+                }
+                b if b == b'`' || is_delimiter(b) => break,
+                _ => self.pos += 1,
+            }
+        }
+        Some(Inline::Plain(self.text.slice(start, self.pos)))
+    }
+}
+
+impl Text {
+    /// Splits the [`Text`] into Markdown inline runs: code spans, emphasis delimiter runs and plain text.
+    /// This is only a tokenizer; it does not pair delimiters or parse links.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{Inline, Text};
+    ///
+    /// let text = Text::new("use `x` *now*");
+    /// let runs: Vec<Inline> = text.markdown_inlines().collect();
+    /// assert_eq!(6, runs.len());
+    /// assert_eq!(Inline::Code { raw: Text::new("`x`"), content: Text::new("x") }, runs[1]);
+    /// assert_eq!(Inline::Delimiter(Text::new("*")), runs[3]);
+    /// ```
+    pub fn markdown_inlines(&self) -> impl Iterator<Item = Inline> + '_ {
+        MarkdownInlines { text: self, pos: 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Inline, Text};
+
+    #[test]
+    pub fn test_markdown_inlines() {
+        let t = Text::new("a \\*b\\* ``c ` d`` __e__ `f ~~g~~");
+        let runs: Vec<Inline> = t.markdown_inlines().collect();
+        let raw: Vec<&str> = runs.iter().map(|r| r.raw().as_str()).collect();
+        assert_eq!(
+            vec![
+                "a \\*b\\* ",
+                "``c ` d``",
+                " ",
+                "__",
+                "e",
+                "__",
+                " ",
+                "`f ",
+                "~~",
+                "g",
+                "~~"
+            ],
+            raw
+        );
+        match &runs[1] {
+            Inline::Code { content, .. } => assert_eq!("c ` d", content.as_str()),
+            other => panic!("Expected code span, got {:?}", other),
+        }
+        assert!(matches!(runs[7], Inline::Plain(_)));
+        assert_eq!(t.as_str(), raw.concat());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::Text;
+use std::ops::Range;
+
+impl Text {
+    /// Replaces every `char` within the given byte ranges by `with`, e.g. to scrub secrets before logging.
+    /// Ranges may overlap and be given in any order. If there is nothing to mask, `self` is returned without copying.
+    /// Will panic if a range exceeds the [`Text`]'s bounds or does not start and end on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let line = Text::new("user=bob password=hunter2");
+    /// assert_eq!("user=••• password=•••••••", line.mask(vec![5..8, 18..25], '•').as_str());
+    /// ```
+    pub fn mask<I: IntoIterator<Item = Range<usize>>>(&self, ranges: I, with: char) -> Text {
+        let s = self.as_str();
+        let mut ranges: Vec<Range<usize>> = ranges.into_iter().filter(|r| !r.is_empty()).collect();
+        for r in &ranges {
+            // Validates the range, panicking with a descriptive message
+            self.slice(r.start, r.end);
+        }
+        if ranges.is_empty() {
+            return self.clone();
+        }
+        ranges.sort_unstable_by_key(|r| r.start);
+        let mut masked = String::with_capacity(s.len());
+        let mut copied = 0;
+        for r in ranges {
+            if r.end <= copied {
+                continue;
+            }
+            let start = r.start.max(copied);
+            masked.push_str(&s[copied..start]);
+            masked.extend(s[start..r.end].chars().map(|_| with));
+            copied = r.end;
+        }
+        masked.push_str(&s[copied..]);
+        Text::new(masked.as_str())
+    }
+
+    /// Masks every occurrence of `pattern` with `with`, see [`Text::mask`].
+    /// If `pattern` does not occur, `self` is returned without copying.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let line = Text::new("token abc123 sent, token abc123 used");
+    /// assert_eq!("token ****** sent, token ****** used", line.redact("abc123", '*').as_str());
+    /// ```
+    pub fn redact(&self, pattern: &str, with: char) -> Text {
+        self.mask(self.find_all_spans(pattern), with)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_mask() {
+        let t = Text::new("abcdéfgh");
+        assert_eq!("a##d#fgh", t.mask(vec![4..6, 1..3], '#').as_str());
+        assert_eq!("a####fgh", t.mask(vec![1..4, 2..6], '#').as_str());
+        assert_eq!("ab••éfgh", t.mask(Some(2..4), '•').as_str());
+        let unchanged = t.mask(Some(3..3), '#');
+        assert_eq!(t.as_str().as_ptr(), unchanged.as_str().as_ptr());
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_mask_invalid() {
+        Text::new("é").mask(Some(0..1), '#');
+    }
+
+    #[test]
+    pub fn test_redact() {
+        let t = Text::new("aaaa");
+        assert_eq!("xxxx", t.redact("aa", 'x').as_str());
+        let unchanged = t.redact("b", 'x');
+        assert_eq!(t.as_str().as_ptr(), unchanged.as_str().as_ptr());
+    }
+}
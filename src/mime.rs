@@ -0,0 +1,150 @@
+use crate::{Text, TextSeq};
+use encoding_rs::Encoding;
+
+/// Decodes standard base64, with optional padding.
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(s.len() / 4 * 3);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for b in s.trim_end_matches('=').bytes() {
+        let value = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Some(out)
+}
+
+/// Decodes the "Q" encoding of RFC 2047, a variant of quoted-printable where `_` stands for a space.
+fn decode_q(s: &str) -> Option<Vec<u8>> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'_' => out.push(b' '),
+            b'=' => {
+                // `from_str_radix` would also accept a sign, as in `=+F`
+                let hex = s.get(i + 1..i + 3)?;
+                if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return None;
+                }
+                out.push(u8::from_str_radix(hex, 16).ok()?);
+                i += 2;
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    Some(out)
+}
+
+/// Parses an encoded word `=?charset?encoding?text?=` starting at `start`,
+/// returning the offset after it and the decoded text.
+fn parse_encoded_word(s: &str, start: usize) -> Option<(usize, String)> {
+    let rest = s[start..].strip_prefix("=?")?;
+    let (charset, rest) = rest.split_once('?')?;
+    let (encoding, rest) = rest.split_once('?')?;
+    let payload_len = rest.find("?=")?;
+    let payload = &rest[..payload_len];
+    if charset.is_empty() || charset.contains(char::is_whitespace) || payload.contains(' ') {
+        return None;
+    }
+    let bytes = match encoding {
+        "B" | "b" => decode_base64(payload)?,
+        "Q" | "q" => decode_q(payload)?,
+        _ => return None,
+    };
+    // RFC 2231 allows a language after the charset, as in `UTF-8*en`
+    let charset = charset.split('*').next()?;
+    let (decoded, _) = Encoding::for_label(charset.as_bytes())?.decode_without_bom_handling(&bytes);
+    // `rest` is a suffix of `s`, starting at the payload
+    let end = s.len() - rest.len() + payload_len + 2;
+    Some((end, decoded.into_owned()))
+}
+
+impl Text {
+    /// Decodes the RFC 2047 encoded words (`=?charset?B?...?=` or `=?charset?Q?...?=`) in a mail header value.
+    /// Text outside of encoded words is kept as slices of `self`, so only the encoded words are allocated;
+    /// whitespace between adjacent encoded words is dropped. Malformed encoded words and unknown charsets are kept as they are.
+    /// Requires the `encoding_rs` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let subject = Text::new("Re: =?UTF-8?B?R3LDvMOfZQ==?= =?ISO-8859-1?Q?aus_K=F6ln?= (fwd)");
+    /// let decoded = subject.decode_encoded_words();
+    /// assert_eq!("Re: Grüßeaus Köln (fwd)", decoded.to_string());
+    /// assert_eq!(subject.as_str().as_ptr(), decoded.pieces()[0].as_str().as_ptr());
+    /// ```
+    pub fn decode_encoded_words(&self) -> TextSeq {
+        let s = self.as_str();
+        let mut seq = TextSeq::new();
+        let mut literal_start = 0;
+        let mut pos = 0;
+        let mut after_word = false;
+        while let Some(i) = s[pos..].find("=?") {
+            let at = pos + i;
+            match parse_encoded_word(s, at) {
+                Some((end, decoded)) => {
+                    let between = &s[literal_start..at];
+                    let adjacent = after_word
+                        && between
+                            .trim_matches(&[' ', '\t', '\r', '\n'][..])
+                            .is_empty();
+                    if !adjacent && !between.is_empty() {
+                        seq.push(self.slice(literal_start, at));
+                    }
+                    seq.push(Text::from_owner(decoded));
+                    literal_start = end;
+                    pos = end;
+                    after_word = true;
+                }
+                None => pos = at + 2,
+            }
+        }
+        if literal_start < s.len() || seq.pieces().is_empty() {
+            seq.push(self.slice(literal_start, s.len()));
+        }
+        seq
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    fn decode(s: &str) -> String {
+        Text::new(s).decode_encoded_words().to_string()
+    }
+
+    #[test]
+    pub fn test_decode_encoded_words() {
+        assert_eq!("plain", decode("plain"));
+        assert_eq!("", decode(""));
+        assert_eq!("ab", decode("=?utf-8?q?a?=\r\n =?utf-8*en?b?Yg?="));
+        assert_eq!("a =?x?q?b?= c", decode("=?utf-8?q?a?= =?x?q?b?= c"));
+        assert_eq!("=?utf-8?q?a b?=", decode("=?utf-8?q?a b?="));
+        assert_eq!("=?utf-8?x?a?=", decode("=?utf-8?x?a?="));
+        assert_eq!("=?utf-8?b?Y!?=", decode("=?utf-8?b?Y!?="));
+        assert_eq!("=?utf-8?q?=4?=", decode("=?utf-8?q?=4?="));
+        assert_eq!("=?utf-8?q?=+F?=", decode("=?utf-8?q?=+F?="));
+        assert_eq!("x y_z", decode("x =?utf-8?Q?y=5Fz?="));
+        let t = Text::new("no words");
+        assert_eq!(
+            t.as_str().as_ptr(),
+            t.decode_encoded_words().to_text().as_str().as_ptr()
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use crate::Text;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
+
+/// A normalization applied to a [`Text`] before comparing or hashing it in a [`NormalizedText`].
+/// Implemented for all functions `Fn(&Text) -> Text`.
+pub trait Normalizer {
+    /// Returns the normalized form of `text`.
+    fn normalize(&self, text: &Text) -> Text;
+}
+
+impl<F: Fn(&Text) -> Text> Normalizer for F {
+    fn normalize(&self, text: &Text) -> Text {
+        self(text)
+    }
+}
+
+/// Removes leading and trailing whitespace. Never copies.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Trim;
+
+impl Normalizer for Trim {
+    fn normalize(&self, text: &Text) -> Text {
+        text.lift(str::trim)
+    }
+}
+
+/// Trims the text and replaces every run of whitespace inside it by a single space.
+/// Only copies if the text contains whitespace that is not a single space.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CollapseWhitespace;
+
+impl Normalizer for CollapseWhitespace {
+    fn normalize(&self, text: &Text) -> Text {
+        let trimmed = text.lift(str::trim);
+        let s = trimmed.as_str();
+        let mut previous_space = false;
+        let collapsed = s.chars().all(|c| {
+            let ok = c == ' ' && !previous_space || !c.is_whitespace();
+            previous_space = c == ' ';
+            ok
+        });
+        if collapsed {
+            trimmed
+        } else {
+            Text::new(s.split_whitespace().collect::<Vec<_>>().join(" ").as_str())
+        }
+    }
+}
+
+/// Converts the text to lowercase. Only copies if the text contains characters that change when lowercased,
+/// including titlecase characters like `ǅ`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Lowercase;
+
+impl Normalizer for Lowercase {
+    fn normalize(&self, text: &Text) -> Text {
+        let changes = |c: char| !c.to_lowercase().eq(std::iter::once(c));
+        if text.as_str().chars().any(changes) {
+            Text::new(text.as_str().to_lowercase().as_str())
+        } else {
+            text.clone()
+        }
+    }
+}
+
+/// A [`Text`] whose equality and hash are based on a normalized form of it.
+/// The normalized form is computed on first use and cached.
+///
+/// # Example
+/// ```
+/// use quetta::{NormalizedText, Text, Trim};
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(NormalizedText::new(Text::new(" a "), Trim));
+/// assert!(!set.insert(NormalizedText::new(Text::new("a"), Trim)));
+/// ```
+pub struct NormalizedText<N: Normalizer> {
+    text: Text,
+    normalizer: N,
+    normalized: OnceLock<Text>,
+}
+
+impl<N: Normalizer> NormalizedText<N> {
+    /// Wraps `text`, comparing it by the normalization `normalizer`.
+    pub fn new(text: Text, normalizer: N) -> Self {
+        Self {
+            text,
+            normalizer,
+            normalized: OnceLock::new(),
+        }
+    }
+
+    /// Gets the normalization this [`NormalizedText`] compares by.
+    pub fn normalizer(&self) -> &N {
+        &self.normalizer
+    }
+
+    /// Gets the original, unnormalized [`Text`].
+    pub fn text(&self) -> &Text {
+        &self.text
+    }
+
+    /// Gets the normalized [`Text`], computing it if necessary.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{CollapseWhitespace, NormalizedText, Text};
+    ///
+    /// let t = NormalizedText::new(Text::new(" a \t b "), CollapseWhitespace);
+    /// assert_eq!("a b", t.normalized().as_str());
+    /// assert_eq!(" a \t b ", t.text().as_str());
+    /// ```
+    pub fn normalized(&self) -> &Text {
+        self.normalized
+            .get_or_init(|| self.normalizer.normalize(&self.text))
+    }
+
+    /// Unwraps the original [`Text`].
+    pub fn into_inner(self) -> Text {
+        self.text
+    }
+}
+
+impl<N: Normalizer + Clone> Clone for NormalizedText<N> {
+    fn clone(&self) -> Self {
+        Self {
+            text: self.text.clone(),
+            normalizer: self.normalizer.clone(),
+            normalized: self.normalized.clone(),
+        }
+    }
+}
+
+impl<N: Normalizer> Debug for NormalizedText<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.text, f)
+    }
+}
+
+impl<N: Normalizer> PartialEq for NormalizedText<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
+
+impl<N: Normalizer> Eq for NormalizedText<N> {}
+
+impl<N: Normalizer> Hash for NormalizedText<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CollapseWhitespace, Lowercase, NormalizedText, Normalizer, Text, Trim};
+    use std::collections::HashSet;
+
+    #[test]
+    pub fn test_normalizers() {
+        let t = Text::new("  Hello \n  World ");
+        assert_eq!("Hello \n  World", Trim.normalize(&t).as_str());
+        assert_eq!("Hello World", CollapseWhitespace.normalize(&t).as_str());
+        assert_eq!("  hello \n  world ", Lowercase.normalize(&t).as_str());
+        assert_eq!("ǆ", Lowercase.normalize(&Text::new("ǅ")).as_str());
+        let lower = Text::new("ß");
+        assert_eq!(
+            lower.as_str().as_ptr(),
+            Lowercase.normalize(&lower).as_str().as_ptr()
+        );
+        let already = Text::new("a b");
+        let collapsed = CollapseWhitespace.normalize(&already);
+        assert_eq!(already.as_str().as_ptr(), collapsed.as_str().as_ptr());
+    }
+
+    #[test]
+    // The cached normalization never changes the hash, so the interior mutability is harmless.
+    #[allow(clippy::mutable_key_type)]
+    pub fn test_dedup() {
+        let lower_trim = |t: &Text| Lowercase.normalize(&Trim.normalize(t));
+        let words = ["Apple", " apple", "APPLE ", "pear"];
+        let set: HashSet<_> = words
+            .iter()
+            .map(|w| NormalizedText::new(Text::new(*w), lower_trim))
+            .collect();
+        assert_eq!(2, set.len());
+    }
+}
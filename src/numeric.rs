@@ -0,0 +1,183 @@
+use crate::Text;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The error returned by [`Text::pad_numeric`] when the [`Text`] is not an integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotNumericError {
+    text: Text,
+}
+
+impl NotNumericError {
+    /// Gets the rejected [`Text`].
+    pub fn text(&self) -> &Text {
+        &self.text
+    }
+}
+
+impl Display for NotNumericError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Not an integer: {:?}", self.text.as_str())
+    }
+}
+
+impl std::error::Error for NotNumericError {}
+
+fn sign_len(bytes: &[u8]) -> usize {
+    match bytes.first() {
+        Some(b'+') | Some(b'-') => 1,
+        _ => 0,
+    }
+}
+
+fn digits_len(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+impl Text {
+    /// Parses an integer (with optional sign) from the start of the [`Text`],
+    /// returning the value and the rest of the [`Text`] after it.
+    /// Returns `None` if the [`Text`] does not start with an integer or it does not fit into `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("-42px");
+    /// let (value, rest) = text.parse_int_prefix::<i32>().unwrap();
+    /// assert_eq!(-42, value);
+    /// assert_eq!("px", rest.as_str());
+    /// ```
+    pub fn parse_int_prefix<T: FromStr>(&self) -> Option<(T, Text)> {
+        let bytes = self.as_str().as_bytes();
+        let sign = sign_len(bytes);
+        let digits = digits_len(&bytes[sign..]);
+        if digits == 0 {
+            return None;
+        }
+        self.parse_prefix(sign + digits)
+    }
+
+    /// Parses a decimal floating point number (with optional sign, fraction and exponent) from the start of the [`Text`],
+    /// returning the value and the rest of the [`Text`] after it.
+    /// Returns `None` if the [`Text`] does not start with a number.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("2.5e3s");
+    /// let (value, rest) = text.parse_float_prefix::<f64>().unwrap();
+    /// assert_eq!(2500.0, value);
+    /// assert_eq!("s", rest.as_str());
+    /// ```
+    pub fn parse_float_prefix<T: FromStr>(&self) -> Option<(T, Text)> {
+        let bytes = self.as_str().as_bytes();
+        let mut end = sign_len(bytes);
+        let int_digits = digits_len(&bytes[end..]);
+        end += int_digits;
+        let mut frac_digits = 0;
+        if bytes.get(end) == Some(&b'.') {
+            frac_digits = digits_len(&bytes[end + 1..]);
+            if int_digits > 0 || frac_digits > 0 {
+                end += 1 + frac_digits;
+            }
+        }
+        if int_digits == 0 && frac_digits == 0 {
+            return None;
+        }
+        if let Some(b'e') | Some(b'E') = bytes.get(end) {
+            let exp_sign = sign_len(&bytes[end + 1..]);
+            let exp_digits = digits_len(&bytes[end + 1 + exp_sign..]);
+            if exp_digits > 0 {
+                end += 1 + exp_sign + exp_digits;
+            }
+        }
+        self.parse_prefix(end)
+    }
+
+    /// Pads an integer (with optional sign) with zeros after the sign to at least `width` bytes, e.g. to generate keys
+    /// that sort numerically. If the [`Text`] is at least `width` bytes long already, `self` is returned without copying.
+    /// Returns an error if the [`Text`] is not an integer.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!("0042", Text::new("42").pad_numeric(4).unwrap().as_str());
+    /// assert_eq!("-007", Text::new("-7").pad_numeric(4).unwrap().as_str());
+    /// assert!(Text::new("4.2").pad_numeric(4).is_err());
+    /// ```
+    pub fn pad_numeric(&self, width: usize) -> Result<Text, NotNumericError> {
+        let bytes = self.as_str().as_bytes();
+        let sign = sign_len(bytes);
+        let digits = digits_len(&bytes[sign..]);
+        if digits == 0 || sign + digits != bytes.len() {
+            return Err(NotNumericError { text: self.clone() });
+        }
+        if bytes.len() >= width {
+            return Ok(self.clone());
+        }
+        let s = self.as_str();
+        let mut padded = String::with_capacity(width);
+        padded.push_str(&s[..sign]);
+        padded.push_str(&"0".repeat(width - bytes.len()));
+        padded.push_str(&s[sign..]);
+        Ok(Text::new(padded.as_str()))
+    }
+
+    fn parse_prefix<T: FromStr>(&self, end: usize) -> Option<(T, Text)> {
+        let value = self.as_str()[..end].parse().ok()?;
+        Some((value, self.slice(end, self.len())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_parse_int_prefix() {
+        let t = Text::new("+17,3");
+        let (v, rest) = t.parse_int_prefix::<u8>().unwrap();
+        assert_eq!(17, v);
+        assert_eq!(",3", rest.as_str());
+        assert!(Text::new("300").parse_int_prefix::<u8>().is_none());
+        assert!(Text::new("-").parse_int_prefix::<i64>().is_none());
+        assert!(Text::new("x1").parse_int_prefix::<i64>().is_none());
+        let (v, rest) = Text::new("12").parse_int_prefix::<u64>().unwrap();
+        assert_eq!(12, v);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    pub fn test_pad_numeric() {
+        let t = Text::new("x+12x").slice(1, 4);
+        assert_eq!("+0012", t.pad_numeric(5).unwrap().as_str());
+        assert_eq!(
+            t.as_str().as_ptr(),
+            t.pad_numeric(2).unwrap().as_str().as_ptr()
+        );
+        assert_eq!("000", Text::new("0").pad_numeric(3).unwrap().as_str());
+        for invalid in ["", "-", "1-", " 1", "１"] {
+            let err = Text::new(invalid).pad_numeric(3).unwrap_err();
+            assert_eq!(invalid, err.text().as_str());
+        }
+    }
+
+    #[test]
+    pub fn test_parse_float_prefix() {
+        let parse = |s: &str| {
+            Text::new(s)
+                .parse_float_prefix::<f64>()
+                .map(|(v, r)| (v, r.to_string()))
+        };
+        assert_eq!(Some((1.5, "".to_string())), parse("1.5"));
+        assert_eq!(Some((-0.5, "x".to_string())), parse("-.5x"));
+        assert_eq!(Some((3.0, "e".to_string())), parse("3.e"));
+        assert_eq!(Some((3.0, "e+".to_string())), parse("3e+"));
+        assert_eq!(Some((0.03, "".to_string())), parse("3E-2"));
+        assert_eq!(None, parse("."));
+        assert_eq!(None, parse("-e5"));
+    }
+}
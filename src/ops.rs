@@ -0,0 +1,145 @@
+use crate::Text;
+
+/// A fluent chain of transformations on a [`Text`], created by [`Text::ops`].
+/// Once a step fails, e.g. [`TextOps::strip_prefix`] without a matching prefix, all later steps are skipped
+/// and [`TextOps::done`] returns `None`. Steps only copy the [`Text`] if they actually change its contents.
+///
+/// # Example
+/// ```
+/// use quetta::Text;
+///
+/// let header = Text::new("  X-Request-Id ");
+/// let name = header.ops().trim().strip_prefix("X-").lowercase().done();
+/// assert_eq!(Some("request-id"), name.as_ref().map(Text::as_str));
+/// assert_eq!(None, Text::new("Accept").ops().strip_prefix("X-").lowercase().done());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use = "call `done` to get the result of the chain"]
+pub struct TextOps(Option<Text>);
+
+impl TextOps {
+    /// Applies a step to the current [`Text`], unless an earlier step has failed.
+    fn step<F: FnOnce(Text) -> Option<Text>>(self, f: F) -> Self {
+        Self(self.0.and_then(f))
+    }
+
+    /// Removes leading and trailing whitespace, without copying.
+    pub fn trim(self) -> Self {
+        self.step(|t| Some(t.lift(str::trim)))
+    }
+
+    /// Removes leading whitespace, without copying.
+    pub fn trim_start(self) -> Self {
+        self.step(|t| Some(t.lift(str::trim_start)))
+    }
+
+    /// Removes trailing whitespace, without copying.
+    pub fn trim_end(self) -> Self {
+        self.step(|t| Some(t.lift(str::trim_end)))
+    }
+
+    /// Removes `prefix` without copying, failing if the [`Text`] does not start with it.
+    pub fn strip_prefix(self, prefix: &str) -> Self {
+        self.step(|t| {
+            let rest = t.as_str().strip_prefix(prefix)?;
+            t.try_lift_slice(rest)
+        })
+    }
+
+    /// Removes `suffix` without copying, failing if the [`Text`] does not end with it.
+    pub fn strip_suffix(self, suffix: &str) -> Self {
+        self.step(|t| {
+            let rest = t.as_str().strip_suffix(suffix)?;
+            t.try_lift_slice(rest)
+        })
+    }
+
+    /// Slices the [`Text`] by byte offsets like [`Text::try_slice`], failing if the range is invalid.
+    pub fn slice(self, start: usize, end: usize) -> Self {
+        self.step(|t| t.try_slice(start, end).ok())
+    }
+
+    /// Replaces the [`Text`] with a slice of it returned by `f`, failing if `f` returns a foreign `&str`.
+    pub fn lift<F: FnOnce(&str) -> &str>(self, f: F) -> Self {
+        self.step(|t| t.try_lift_slice(f(t.as_str())))
+    }
+
+    /// Fails unless the [`Text`] satisfies `pred`.
+    pub fn filter<P: FnOnce(&str) -> bool>(self, pred: P) -> Self {
+        self.step(|t| if pred(t.as_str()) { Some(t) } else { None })
+    }
+
+    /// Converts the [`Text`] to lowercase, copying only if any `char` changes.
+    pub fn lowercase(self) -> Self {
+        self.step(|t| {
+            let unchanged = t
+                .as_str()
+                .chars()
+                .all(|c| c.to_lowercase().eq(std::iter::once(c)));
+            Some(if unchanged {
+                t
+            } else {
+                Text::new(&*t.as_str().to_lowercase())
+            })
+        })
+    }
+
+    /// Converts the [`Text`] to uppercase, copying only if any `char` changes.
+    pub fn uppercase(self) -> Self {
+        self.step(|t| {
+            let unchanged = t
+                .as_str()
+                .chars()
+                .all(|c| c.to_uppercase().eq(std::iter::once(c)));
+            Some(if unchanged {
+                t
+            } else {
+                Text::new(&*t.as_str().to_uppercase())
+            })
+        })
+    }
+
+    /// Ends the chain, returning the resulting [`Text`] or `None` if any step failed.
+    pub fn done(self) -> Option<Text> {
+        self.0
+    }
+}
+
+impl Text {
+    /// Starts a [`TextOps`] chain of transformations on the [`Text`].
+    pub fn ops(&self) -> TextOps {
+        TextOps(Some(self.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_ops() {
+        let t = Text::new(" [key] ");
+        let key = t
+            .ops()
+            .trim_start()
+            .strip_prefix("[")
+            .trim_end()
+            .strip_suffix("]")
+            .lowercase()
+            .done()
+            .unwrap();
+        assert_eq!("key", key.as_str());
+        assert!(t.contains_slice(&key));
+        assert_eq!(
+            Some(Text::new("ÄB")),
+            Text::new("äb").ops().uppercase().done()
+        );
+        assert_eq!(None, t.ops().slice(0, 10).trim().done());
+        assert_eq!(None, t.ops().lift(|_| "foreign").done());
+        assert_eq!(None, t.ops().filter(str::is_empty).done());
+        assert_eq!(
+            Some(Text::new("e")),
+            t.ops().lift(|s| &s[3..4]).filter(|s| s == "e").done()
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Text;
+use std::io;
+
+/// The maximum number of bytes passed to a writer at once by [`Text::write_to`] and the `Display` implementation.
+const WRITE_CHUNK_LEN: usize = 64 * 1024;
+
+/// Splits `s` into pieces of at most [`WRITE_CHUNK_LEN`] bytes on `char` boundaries.
+pub(crate) fn write_chunks(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let mut end = rest.len().min(WRITE_CHUNK_LEN);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, tail) = rest.split_at(end);
+        rest = tail;
+        Some(chunk)
+    })
+}
+
+impl Text {
+    /// Writes the [`Text`] to `writer` in chunks of bounded size, so that huge texts can be streamed
+    /// without writing them in one call or copying them into an intermediate `String`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let mut out = Vec::new();
+    /// Text::new("streamed").write_to(&mut out).unwrap();
+    /// assert_eq!(b"streamed", out.as_slice());
+    /// ```
+    pub fn write_to<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        for chunk in self.as_str().as_bytes().chunks(WRITE_CHUNK_LEN) {
+            writer.write_all(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_chunks, WRITE_CHUNK_LEN};
+    use crate::Text;
+
+    #[test]
+    pub fn test_write_chunks() {
+        let s = format!("a{}", "ä".repeat(WRITE_CHUNK_LEN));
+        let chunks: Vec<&str> = write_chunks(&s).collect();
+        assert_eq!(3, chunks.len());
+        assert!(chunks.iter().all(|c| c.len() <= WRITE_CHUNK_LEN));
+        assert_eq!(s, chunks.concat());
+        assert_eq!(0, write_chunks("").count());
+        let text = Text::new(s.as_str());
+        assert_eq!(s, text.to_string());
+        let mut out = Vec::new();
+        text.write_to(&mut out).unwrap();
+        assert_eq!(s.as_bytes(), out.as_slice());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::Text;
+
+/// Where [`Text::split_for_parallelism`] may cut the [`Text`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChunkBoundary {
+    /// Cut anywhere between two `char`s.
+    Char,
+    /// Cut only after a `\n`, so no line is split between chunks.
+    Line,
+}
+
+impl Text {
+    /// Splits the [`Text`] into at most `n_chunks` slices of roughly equal size, cut only at the given kind of boundary,
+    /// e.g. to distribute work over threads ([`Text`] is `Send` and `Sync`).
+    /// Concatenating the chunks yields the original [`Text`]; no chunk is empty.
+    /// Fewer chunks are returned if the [`Text`] is too short or (for [`ChunkBoundary::Line`]) has too few lines.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{ChunkBoundary, Text};
+    ///
+    /// let text = Text::new("one\ntwo\nthree\nfour\n");
+    /// let chunks = text.split_for_parallelism(2, ChunkBoundary::Line);
+    /// assert_eq!(2, chunks.len());
+    /// assert_eq!("one\ntwo\nthree\n", chunks[0].as_str());
+    /// assert_eq!("four\n", chunks[1].as_str());
+    /// ```
+    pub fn split_for_parallelism(&self, n_chunks: usize, boundary: ChunkBoundary) -> Vec<Text> {
+        let s = self.as_str();
+        let bytes = s.as_bytes();
+        let n_chunks = n_chunks.max(1);
+        let mut chunks = Vec::with_capacity(n_chunks);
+        let mut start = 0;
+        for i in 1..=n_chunks {
+            if start >= s.len() {
+                break;
+            }
+            let target = (s.len() as u128 * i as u128 / n_chunks as u128) as usize;
+            let mut end = target.max(start);
+            match boundary {
+                ChunkBoundary::Char => {
+                    while !s.is_char_boundary(end) {
+                        end += 1;
+                    }
+                }
+                ChunkBoundary::Line => {
+                    if end > 0 && end < s.len() && bytes[end - 1] != b'\n' {
+                        end = memchr::memchr(b'\n', &bytes[end..]).map_or(s.len(), |p| end + p + 1);
+                    }
+                }
+            }
+            if end > start {
+                chunks.push(self.slice(start, end));
+                start = end;
+            }
+        }
+        chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkBoundary, Text};
+
+    #[test]
+    pub fn test_split_for_parallelism() {
+        let t = Text::new("äöü abc\ndef\n\nghijklmnop");
+        for n in 0..30 {
+            for boundary in [ChunkBoundary::Char, ChunkBoundary::Line] {
+                let chunks = t.split_for_parallelism(n, boundary);
+                assert!(chunks.len() <= n.max(1));
+                assert!(chunks.iter().all(|c| !c.is_empty()));
+                let joined: String = chunks.iter().map(Text::as_str).collect();
+                assert_eq!(t.as_str(), joined);
+                if boundary == ChunkBoundary::Line {
+                    let last = chunks.len() - 1;
+                    assert!(chunks[..last].iter().all(|c| c.as_str().ends_with('\n')));
+                }
+            }
+        }
+        assert_eq!(4, t.split_for_parallelism(4, ChunkBoundary::Char).len());
+        assert!(Text::default()
+            .split_for_parallelism(3, ChunkBoundary::Char)
+            .is_empty());
+    }
+
+    #[test]
+    pub fn test_parallel_use() {
+        let t = Text::new("a b c\nd e\nf g h i\n".repeat(50).as_str());
+        let chunks = t.split_for_parallelism(4, ChunkBoundary::Line);
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| std::thread::spawn(move || chunk.as_str().split_whitespace().count()))
+            .collect();
+        let words: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(450, words);
+    }
+}
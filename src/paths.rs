@@ -0,0 +1,114 @@
+use crate::Text;
+
+/// Gets the length in bytes of the longest common prefix of `a` and `b` that ends on a `char` boundary.
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    let mut len = a.bytes().zip(b.bytes()).take_while(|(x, y)| x == y).count();
+    while !a.is_char_boundary(len) {
+        len -= 1;
+    }
+    len
+}
+
+/// Gets the length in bytes of the longest common suffix of `a` and `b` that starts on a `char` boundary.
+fn common_suffix_len(a: &str, b: &str) -> usize {
+    let mut len = a
+        .bytes()
+        .rev()
+        .zip(b.bytes().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    while !a.is_char_boundary(a.len() - len) {
+        len -= 1;
+    }
+    len
+}
+
+impl Text {
+    /// Removes the longest prefix shared with `other`, returning the remainder as a slice of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let key = Text::new("/users/42/posts");
+    /// assert_eq!("42/posts", key.trim_common_prefix("/users/7").as_str());
+    /// ```
+    pub fn trim_common_prefix(&self, other: &str) -> Text {
+        let s = self.as_str();
+        self.slice(common_prefix_len(s, other), s.len())
+    }
+
+    /// Removes the longest suffix shared with `other`, returning the remainder as a slice of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let file = Text::new("src/lib.rs");
+    /// assert_eq!("src/lib", file.trim_common_suffix("main.rs").as_str());
+    /// ```
+    pub fn trim_common_suffix(&self, other: &str) -> Text {
+        let s = self.as_str();
+        self.slice(0, s.len() - common_suffix_len(s, other))
+    }
+
+    /// Gets the path-style remainder of the [`Text`] below `base`, as a slice of `self`.
+    /// `base` must match whole `/`-separated segments; a trailing `/` on `base` is ignored and the separator is not part of the result.
+    /// Returns `None` if `self` does not lie below `base`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let route = Text::new("/api/v1/users/42");
+    /// assert_eq!("users/42", route.relative_to("/api/v1/").unwrap().as_str());
+    /// assert_eq!("", route.relative_to("/api/v1/users/42").unwrap().as_str());
+    /// assert_eq!(None, route.relative_to("/api/v"));
+    /// ```
+    pub fn relative_to(&self, base: &str) -> Option<Text> {
+        let s = self.as_str();
+        let base = base.strip_suffix('/').unwrap_or(base);
+        let rest = s.strip_prefix(base)?;
+        let start = if rest.is_empty() {
+            s.len()
+        } else if rest.starts_with('/') {
+            base.len() + 1
+        } else if base.is_empty() {
+            // An empty base is the root, below which everything lies
+            0
+        } else {
+            return None;
+        };
+        Some(self.slice(start, s.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_trim_common() {
+        let t = Text::new("xaé/bx").slice(1, 6);
+        assert_eq!("é/b", t.trim_common_prefix("a").as_str());
+        assert_eq!("é/b", t.trim_common_prefix("aè").as_str());
+        assert_eq!("", t.trim_common_prefix("aé/b/c").as_str());
+        assert_eq!("aé/b", t.trim_common_suffix("").as_str());
+        assert_eq!("a", t.trim_common_suffix("é/b").as_str());
+        assert_eq!("aé", t.trim_common_suffix("è/b").as_str());
+        assert_eq!("", t.trim_common_suffix("xaé/b").as_str());
+    }
+
+    #[test]
+    pub fn test_relative_to() {
+        let t = Text::new("a/b/c");
+        let relative = |base: &str| t.relative_to(base).map(|r| r.to_string());
+        assert_eq!(Some(String::from("b/c")), relative("a"));
+        assert_eq!(Some(String::from("c")), relative("a/b/"));
+        assert_eq!(Some(String::from("a/b/c")), relative(""));
+        assert_eq!(None, relative("a/b/c/d"));
+        assert_eq!(None, relative("b"));
+        let absolute = Text::new("/a/b");
+        assert_eq!("a/b", absolute.relative_to("/").unwrap().as_str());
+    }
+}
@@ -0,0 +1,75 @@
+use crate::Text;
+
+/// Is `c` a metacharacter in the syntax of the `regex` crate (and most other regex engines)?
+fn is_meta_char(c: char) -> bool {
+    matches!(
+        c,
+        '\\' | '.'
+            | '+'
+            | '*'
+            | '?'
+            | '('
+            | ')'
+            | '|'
+            | '['
+            | ']'
+            | '{'
+            | '}'
+            | '^'
+            | '$'
+            | '#'
+            | '&'
+            | '-'
+            | '~'
+    )
+}
+
+impl Text {
+    /// Escapes all regular expression metacharacters with a backslash, so that the [`Text`] matches itself literally
+    /// when embedded into a pattern. The escaped set is the same as `regex::escape`'s.
+    /// If no metacharacters occur, `self` is returned without copying.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let user_input = Text::new("1+1=2?");
+    /// assert_eq!(r"1\+1=2\?", user_input.escape_regex().as_str());
+    /// ```
+    pub fn escape_regex(&self) -> Text {
+        let s = self.as_str();
+        let first = match s.find(is_meta_char) {
+            Some(first) => first,
+            None => return self.clone(),
+        };
+        let mut escaped = String::with_capacity(s.len() + 8);
+        escaped.push_str(&s[..first]);
+        for c in s[first..].chars() {
+            if is_meta_char(c) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        Text::new(escaped.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_escape_regex() {
+        let t = Text::new("a.b");
+        assert_eq!(r"a\.b", t.escape_regex().as_str());
+        let plain = t.slice(0, 1);
+        assert_eq!(
+            plain.as_str().as_ptr(),
+            plain.escape_regex().as_str().as_ptr()
+        );
+        assert_eq!(
+            r"\\\.\+\*\?\(\)\|\[\]\{\}\^\$\#\&\-\~ ä",
+            Text::new(r"\.+*?()|[]{}^$#&-~ ä").escape_regex().as_str()
+        );
+    }
+}
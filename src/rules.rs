@@ -0,0 +1,134 @@
+use crate::Text;
+use std::iter::FromIterator;
+
+/// A set of suffix rewriting rules, applied with [`Text::apply_rules`].
+/// When several suffixes match, the longest one wins; among equally long suffixes, the one added first wins.
+///
+/// # Example
+/// ```
+/// use quetta::{RuleSet, Text};
+///
+/// let plural: RuleSet = vec![("", "s"), ("y", "ies"), ("ey", "eys"), ("s", "ses"), ("ch", "ches")]
+///     .into_iter()
+///     .collect();
+/// assert_eq!("cities", Text::new("city").apply_rules(&plural).as_str());
+/// assert_eq!("keys", Text::new("key").apply_rules(&plural).as_str());
+/// assert_eq!("matches", Text::new("match").apply_rules(&plural).as_str());
+/// assert_eq!("tokens", Text::new("token").apply_rules(&plural).as_str());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    /// Rules ordered by descending suffix length, stable with respect to insertion order.
+    rules: Vec<(String, String)>,
+}
+
+impl RuleSet {
+    /// Creates an empty [`RuleSet`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule replacing `suffix` by `replacement`.
+    /// An empty `suffix` matches every [`Text`] and can be used as a fallback.
+    pub fn add(&mut self, suffix: &str, replacement: &str) {
+        let index = self
+            .rules
+            .iter()
+            .position(|(s, _)| s.len() < suffix.len())
+            .unwrap_or(self.rules.len());
+        self.rules
+            .insert(index, (suffix.to_string(), replacement.to_string()));
+    }
+
+    /// Gets the number of rules.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Are there no rules?
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    fn find(&self, s: &str) -> Option<(&str, &str)> {
+        self.rules
+            .iter()
+            .find(|(suffix, _)| s.ends_with(suffix.as_str()))
+            .map(|(s, r)| (s.as_str(), r.as_str()))
+    }
+}
+
+impl<S: AsRef<str>, R: AsRef<str>> FromIterator<(S, R)> for RuleSet {
+    fn from_iter<T: IntoIterator<Item = (S, R)>>(iter: T) -> Self {
+        let mut rules = RuleSet::new();
+        for (suffix, replacement) in iter {
+            rules.add(suffix.as_ref(), replacement.as_ref());
+        }
+        rules
+    }
+}
+
+impl Text {
+    /// Rewrites the suffix of the [`Text`] according to the best matching rule in `rules`.
+    /// No copy is made if no rule matches, or if the matching rule only removes characters.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{RuleSet, Text};
+    ///
+    /// let mut singular = RuleSet::new();
+    /// singular.add("s", "");
+    /// singular.add("ies", "y");
+    /// assert_eq!("token", Text::new("tokens").apply_rules(&singular).as_str());
+    /// assert_eq!("city", Text::new("cities").apply_rules(&singular).as_str());
+    /// assert_eq!("sheep", Text::new("sheep").apply_rules(&singular).as_str());
+    /// ```
+    pub fn apply_rules(&self, rules: &RuleSet) -> Text {
+        let s = self.as_str();
+        match rules.find(s) {
+            None => self.clone(),
+            Some((suffix, replacement)) if suffix == replacement => self.clone(),
+            Some((suffix, replacement)) => {
+                let stem = s.len() - suffix.len();
+                if replacement.is_empty() {
+                    self.slice(0, stem)
+                } else {
+                    let mut out = String::with_capacity(stem + replacement.len());
+                    out.push_str(&s[..stem]);
+                    out.push_str(replacement);
+                    Text::new(out.as_str())
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RuleSet, Text};
+
+    #[test]
+    pub fn test_rule_priority() {
+        let mut rules = RuleSet::new();
+        rules.add("a", "1");
+        rules.add("ba", "2");
+        rules.add("a", "3");
+        rules.add("", "4");
+        assert_eq!(4, rules.len());
+        assert_eq!("x1", Text::new("xa").apply_rules(&rules).as_str());
+        assert_eq!("x2", Text::new("xba").apply_rules(&rules).as_str());
+        assert_eq!("x4", Text::new("x").apply_rules(&rules).as_str());
+    }
+
+    #[test]
+    pub fn test_zero_copy() {
+        let rules: RuleSet = vec![("s", ""), ("us", "us")].into_iter().collect();
+        let t = Text::new("status");
+        assert_eq!(t.as_str().as_ptr(), t.apply_rules(&rules).as_str().as_ptr());
+        let t = Text::new("cats");
+        let singular = t.apply_rules(&rules);
+        assert_eq!("cat", singular.as_str());
+        assert_eq!(t.as_str().as_ptr(), singular.as_str().as_ptr());
+        assert!(RuleSet::new().is_empty());
+    }
+}
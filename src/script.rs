@@ -0,0 +1,70 @@
+use crate::Text;
+use unicode_script::{Script, ScriptExtension, UnicodeScript};
+
+impl Text {
+    /// Gets the Unicode scripts used in the [`Text`] together with the number of `char`s in each,
+    /// ordered from most to least frequent (ties in order of first appearance), so the dominant script comes first.
+    /// Characters shared between scripts (`Common` and `Inherited`, e.g. digits, punctuation and combining marks) are not counted.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{Script, Text};
+    ///
+    /// let name = Text::new("pаypal 2");
+    /// assert_eq!(vec![(Script::Latin, 5), (Script::Cyrillic, 1)], name.scripts());
+    /// ```
+    pub fn scripts(&self) -> Vec<(Script, usize)> {
+        let mut counts: Vec<(Script, usize)> = Vec::new();
+        for c in self.as_str().chars() {
+            let script = c.script();
+            if script == Script::Common || script == Script::Inherited {
+                continue;
+            }
+            match counts.iter_mut().find(|(s, _)| *s == script) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((script, 1)),
+            }
+        }
+        counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+        counts
+    }
+
+    /// Gets the most frequent script of the [`Text`] (see [`Text::scripts`]),
+    /// or `None` if it consists only of characters shared between scripts.
+    pub fn dominant_script(&self) -> Option<Script> {
+        self.scripts().first().map(|(script, _)| *script)
+    }
+
+    /// Is the [`Text`] written in a single script? Following UTS #39, characters used by several scripts
+    /// (like `Common` punctuation or the prolonged sound mark of Hiragana and Katakana) are compatible with each of them.
+    /// Mixed-script identifiers are a common sign of spoofing.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert!(Text::new("paypal_2").is_single_script());
+    /// assert!(!Text::new("pаypal").is_single_script());
+    /// ```
+    pub fn is_single_script(&self) -> bool {
+        !ScriptExtension::for_str(self.as_str()).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Script, Text};
+
+    #[test]
+    pub fn test_scripts() {
+        let t = Text::new("Москва (Moscow), 2024 — Мск");
+        assert_eq!(vec![(Script::Cyrillic, 9), (Script::Latin, 6)], t.scripts());
+        assert_eq!(Some(Script::Cyrillic), t.dominant_script());
+        assert!(!t.is_single_script());
+        assert_eq!(None, Text::new("123 - !").dominant_script());
+        assert!(Text::new("123 - !").is_single_script());
+        assert!(Text::new("すーぱー").is_single_script());
+        assert!(Text::new("タワー").is_single_script());
+        assert!(Text::new("e\u{301}cole").is_single_script());
+    }
+}
@@ -0,0 +1,97 @@
+use crate::Text;
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, BuildError, MatchKind};
+
+/// Searches a [`Text`] for many patterns at once, using the Aho-Corasick algorithm.
+/// Requires the `aho-corasick` feature.
+///
+/// # Example
+/// ```
+/// use quetta::{Text, TextSearcher};
+///
+/// let searcher = TextSearcher::new(&["fn", "let"]).unwrap();
+/// let text = Text::new("fn main() { let x = 1; }");
+/// let matches: Vec<(usize, Text)> = searcher.find_iter(&text).collect();
+/// assert_eq!(2, matches.len());
+/// assert_eq!(1, matches[1].0);
+/// assert_eq!("let", matches[1].1.as_str());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextSearcher {
+    automaton: AhoCorasick,
+}
+
+impl TextSearcher {
+    /// Builds a searcher for the given patterns.
+    /// Returns an error if the automaton exceeds the size limits of `aho-corasick`.
+    pub fn new<I, P>(patterns: I) -> Result<Self, BuildError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let patterns: Vec<P> = patterns.into_iter().collect();
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::LeftmostFirst)
+            .build(patterns.iter().map(|p| p.as_ref().as_bytes()))?;
+        Ok(Self { automaton })
+    }
+
+    /// Gets the number of patterns this searcher looks for.
+    pub fn patterns_len(&self) -> usize {
+        self.automaton.patterns_len()
+    }
+
+    /// Finds all non-overlapping matches in `text`, yielding the index of the matched pattern
+    /// and the match as a slice of `text`.
+    /// When multiple patterns match at the same position, the one given first to [`TextSearcher::new`] wins.
+    /// Matches that do not start and end on `char` boundaries, like those of an empty pattern inside a multibyte `char`,
+    /// are skipped.
+    pub fn find_iter<'a>(&'a self, text: &'a Text) -> impl Iterator<Item = (usize, Text)> + 'a {
+        self.automaton
+            .find_iter(text.as_str())
+            .filter_map(move |m| {
+                let found = text.try_slice(m.start(), m.end()).ok()?;
+                Some((m.pattern().as_usize(), found))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Text, TextSearcher};
+
+    #[test]
+    pub fn test_find_iter() {
+        let searcher = TextSearcher::new(vec!["ab", "abc", "ü"]).unwrap();
+        assert_eq!(3, searcher.patterns_len());
+        let t = Text::new("xabcüab").slice(1, 8);
+        let matches: Vec<(usize, Text)> = searcher.find_iter(&t).collect();
+        let found: Vec<(usize, &str)> = matches.iter().map(|(i, m)| (*i, m.as_str())).collect();
+        assert_eq!(vec![(0, "ab"), (2, "ü"), (0, "ab")], found);
+    }
+
+    #[test]
+    pub fn test_empty_pattern() {
+        let searcher = TextSearcher::new([""]).unwrap();
+        let t = Text::new("üa");
+        let starts: Vec<usize> = searcher
+            .find_iter(&t)
+            .map(|(_, m)| t.byte_offset_of(&m).unwrap())
+            .collect();
+        assert_eq!(vec![0, 2, 3], starts);
+    }
+
+    #[test]
+    pub fn test_first_pattern_wins() {
+        let t = Text::new("abcd");
+        let found = |patterns: &[&str]| -> Vec<(usize, String)> {
+            let searcher = TextSearcher::new(patterns).unwrap();
+            searcher
+                .find_iter(&t)
+                .map(|(i, m)| (i, m.to_string()))
+                .collect()
+        };
+        assert_eq!(vec![(0, "abc".to_string())], found(&["abc", "ab"]));
+        assert_eq!(vec![(0, "ab".to_string())], found(&["ab", "abc"]));
+        assert_eq!(vec![(1, "abcd".to_string())], found(&["bcd", "abcd"]));
+    }
+}
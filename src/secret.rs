@@ -0,0 +1,103 @@
+use crate::Text;
+use std::fmt::{Debug, Formatter};
+use subtle::ConstantTimeEq;
+
+impl Text {
+    /// Compares two texts in time independent of their contents, to avoid leaking secrets like tokens through timing.
+    /// Only the lengths are compared in variable time, so texts of different lengths are rejected early.
+    /// Requires the `subtle` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let token = Text::new("s3cr3t");
+    /// assert!(token.ct_eq(&Text::new("s3cr3t")));
+    /// assert!(!token.ct_eq(&Text::new("s3cr3T")));
+    /// ```
+    pub fn ct_eq(&self, other: &Text) -> bool {
+        self.as_str()
+            .as_bytes()
+            .ct_eq(other.as_str().as_bytes())
+            .into()
+    }
+}
+
+/// A [`Text`] holding a secret like a password or token. It is compared in constant time with [`Text::ct_eq`],
+/// is redacted when debug-printed, and overwrites its buffer with zeros when dropped if it holds the only handle to it
+/// and the bytes are stored inline or owned by a `String`. Requires the `subtle` feature.
+///
+/// # Example
+/// ```
+/// use quetta::{SecretText, Text};
+///
+/// let password = SecretText::new(Text::new("hunter2"));
+/// assert_eq!("SecretText(***)", format!("{:?}", password));
+/// assert_eq!("hunter2", password.expose().as_str());
+/// assert_eq!(password, SecretText::new(Text::new("hunter2")));
+/// ```
+#[derive(Clone)]
+pub struct SecretText(Text);
+
+impl SecretText {
+    /// Wraps a [`Text`] holding a secret.
+    pub fn new(text: Text) -> Self {
+        Self(text)
+    }
+
+    /// Gets the secret. Be careful not to keep clones of it around, as they prevent wiping the buffer on drop.
+    pub fn expose(&self) -> &Text {
+        &self.0
+    }
+}
+
+impl From<Text> for SecretText {
+    fn from(text: Text) -> Self {
+        Self::new(text)
+    }
+}
+
+impl Debug for SecretText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretText(***)")
+    }
+}
+
+impl PartialEq for SecretText {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl Eq for SecretText {}
+
+impl Drop for SecretText {
+    fn drop(&mut self) {
+        self.0 .0.string.wipe_if_unique();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SecretText, Text};
+
+    #[test]
+    pub fn test_ct_eq() {
+        let t = Text::new("xabx");
+        assert!(t.slice(1, 3).ct_eq(&Text::new("ab")));
+        assert!(!t.ct_eq(&Text::new("xab")));
+        assert!(Text::default().ct_eq(&Text::new("")));
+    }
+
+    #[test]
+    pub fn test_secret_text() {
+        let text = Text::new("pw");
+        let secret = SecretText::from(text.slice(0, 2));
+        let clone = secret.clone();
+        drop(secret);
+        assert_eq!("pw", clone.expose().as_str());
+        drop(clone);
+        assert_eq!("pw", text.as_str());
+        assert_ne!(SecretText::new(text), SecretText::new(Text::new("pW")));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::Text;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+
+/// A sequence of [`Text`] pieces treated as one logical text, without copying the pieces into a single buffer.
+/// Use [`TextSeq::to_text`] to flatten it once a contiguous [`Text`] is actually needed.
+/// Equality and hashing consider only the content, not how it is split into pieces.
+///
+/// # Example
+/// ```
+/// use quetta::{Text, TextSeq};
+///
+/// let mut seq = TextSeq::new();
+/// seq.push(Text::new("hello"));
+/// seq.push(Text::new(", world"));
+/// assert_eq!(12, seq.len());
+/// assert_eq!("hello, world", seq.to_string());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TextSeq {
+    pieces: Vec<Text>,
+    len: usize,
+}
+
+impl TextSeq {
+    /// Creates an empty [`TextSeq`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a piece to the end of the sequence. If it directly follows the last piece in the same buffer,
+    /// the two are merged into a single slice, so re-assembling tokens sliced from one source collapses back into few pieces.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{Text, TextSeq};
+    ///
+    /// let source = Text::new("let x = 1;");
+    /// let seq: TextSeq = source.split_terminator_char(' ').collect();
+    /// assert_eq!(4, seq.pieces().len());
+    /// let mut seq = TextSeq::new();
+    /// seq.push(source.slice(0, 3));
+    /// seq.push(source.slice(3, 5));
+    /// seq.push(Text::new("!"));
+    /// assert_eq!(2, seq.pieces().len());
+    /// assert_eq!("let x", seq.pieces()[0].as_str());
+    /// ```
+    pub fn push(&mut self, text: Text) {
+        self.len += text.len();
+        if let Some(last) = self.pieces.last_mut() {
+            if let Some(merged) = Text::checked_concat_adjacent(last, &text) {
+                *last = merged;
+                return;
+            }
+        }
+        self.pieces.push(text);
+    }
+
+    /// Gets the total length of all pieces in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Is the total length of all pieces 0?
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the pieces in order.
+    pub fn pieces(&self) -> &[Text] {
+        &self.pieces
+    }
+
+    fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.pieces.iter().flat_map(|piece| piece.as_str().bytes())
+    }
+
+    /// Concatenates all pieces into a single [`Text`]. A single piece is returned without copying.
+    pub fn to_text(&self) -> Text {
+        match self.pieces.as_slice() {
+            [single] => single.clone(),
+            pieces => Text::concat(pieces),
+        }
+    }
+}
+
+impl PartialEq for TextSeq {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.bytes().eq(other.bytes())
+    }
+}
+
+impl Eq for TextSeq {}
+
+impl Hash for TextSeq {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hashed byte by byte, as hashers may treat differently split writes differently
+        state.write_usize(self.len);
+        self.bytes().for_each(|b| state.write_u8(b));
+    }
+}
+
+impl Display for TextSeq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Padding and truncation need the whole text, but plain formatting can write the pieces one by one
+        if f.width().is_some() || f.precision().is_some() {
+            return f.pad(self.to_text().as_str());
+        }
+        self.pieces
+            .iter()
+            .try_for_each(|piece| f.write_str(piece.as_str()))
+    }
+}
+
+impl Extend<Text> for TextSeq {
+    fn extend<I: IntoIterator<Item = Text>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|text| self.push(text))
+    }
+}
+
+impl FromIterator<Text> for TextSeq {
+    fn from_iter<I: IntoIterator<Item = Text>>(iter: I) -> Self {
+        let mut seq = TextSeq::new();
+        seq.extend(iter);
+        seq
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Text, TextSeq};
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[test]
+    pub fn test_text_seq() {
+        let source = Text::new("a b c");
+        let seq: TextSeq = source.split_terminator_char(' ').collect();
+        assert_eq!(3, seq.pieces().len());
+        let merged: TextSeq = (0..5).map(|i| source.slice(i, i + 1)).collect();
+        assert_eq!(vec![source.clone()], merged.pieces());
+        assert_eq!(3, seq.len());
+        assert_eq!("abc", seq.to_text().as_str());
+        assert_eq!("ab|", format!("{:|<3.2}", seq));
+        let single: TextSeq = std::iter::once(source.clone()).collect();
+        assert_eq!(source.as_str().as_ptr(), single.to_text().as_str().as_ptr());
+        assert!(TextSeq::new().is_empty());
+        assert_eq!("", TextSeq::new().to_text().as_str());
+        let shared: &'static str = "abcdef";
+        let owned = [
+            Text::from_owner(&shared[..3]),
+            Text::from_owner(shared).slice(3, 6),
+        ];
+        let seq: TextSeq = owned.iter().cloned().collect();
+        assert_eq!(2, seq.pieces().len());
+        assert_eq!("abcdef", seq.to_string());
+    }
+
+    #[test]
+    pub fn test_content_equality() {
+        let split: TextSeq = vec![Text::new("a"), Text::new("b")].into_iter().collect();
+        let whole: TextSeq = std::iter::once(Text::new("ab")).collect();
+        assert_eq!(split, whole);
+        assert_ne!(split, std::iter::once(Text::new("ba")).collect());
+        let hash = |seq: &TextSeq| {
+            let mut hasher = DefaultHasher::new();
+            seq.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&split), hash(&whole));
+    }
+}
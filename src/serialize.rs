@@ -0,0 +1,131 @@
+use crate::{check_len, Interner, Text};
+use serde::de::{DeserializeSeed, Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Formatter;
+
+impl Serialize for Text {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct TextVisitor;
+
+impl<'de> Visitor<'de> for TextVisitor {
+    type Value = Text;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Text, E> {
+        Text::try_new(v).map_err(E::custom)
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Text, E> {
+        // Keeps the deserializer's buffer instead of copying it
+        check_len(v.len()).map_err(E::custom)?;
+        Ok(Text::from_owner(v))
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Text, E> {
+        let s = std::str::from_utf8(v).map_err(E::custom)?;
+        self.visit_str(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Text {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_string(TextVisitor)
+    }
+}
+
+/// Deserializes a string into a [`Text`] interned with the given [`Interner`], so that repeated strings share one buffer.
+/// Use it from the `Visitor` of a containing type, e.g. with `SeqAccess::next_element_seed`.
+/// Requires the `serde` feature.
+///
+/// # Example
+/// ```
+/// use quetta::{InternedTextSeed, Interner};
+/// use serde::de::DeserializeSeed;
+///
+/// let mut interner = Interner::new();
+/// let mut json = serde_json::Deserializer::from_str(r#""kind" "kind""#);
+/// let a = InternedTextSeed(&mut interner).deserialize(&mut json).unwrap();
+/// let b = InternedTextSeed(&mut interner).deserialize(&mut json).unwrap();
+/// assert_eq!("kind", a.as_str());
+/// assert_eq!(a.as_str().as_ptr(), b.as_str().as_ptr());
+/// ```
+pub struct InternedTextSeed<'a>(pub &'a mut Interner);
+
+impl<'de> DeserializeSeed<'de> for InternedTextSeed<'_> {
+    type Value = Text;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Text, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for InternedTextSeed<'_> {
+    type Value = Text;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Text, E> {
+        Ok(self.0.intern(v))
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Text, E> {
+        let s = std::str::from_utf8(v).map_err(E::custom)?;
+        self.visit_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InternedTextSeed, Interner, Text};
+    use serde::de::{SeqAccess, Visitor};
+    use serde::Deserializer;
+    use std::fmt::Formatter;
+
+    #[test]
+    pub fn test_round_trip() {
+        let texts = vec![Text::new("añb"), Text::new("a\"b").slice(1, 3)];
+        let json = serde_json::to_string(&texts).unwrap();
+        assert_eq!(r#"["añb","\"b"]"#, json);
+        let decoded: Vec<Text> = serde_json::from_str(&json).unwrap();
+        assert_eq!(texts, decoded);
+        assert!(serde_json::from_str::<Text>("1").is_err());
+    }
+
+    struct InternedSeq<'a>(&'a mut Interner);
+
+    impl<'de> Visitor<'de> for InternedSeq<'_> {
+        type Value = Vec<Text>;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_str("a list of strings")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<Text>, A::Error> {
+            let mut texts = Vec::new();
+            while let Some(text) = seq.next_element_seed(InternedTextSeed(self.0))? {
+                texts.push(text);
+            }
+            Ok(texts)
+        }
+    }
+
+    #[test]
+    pub fn test_interned_seed() {
+        let mut interner = Interner::new();
+        let mut json = serde_json::Deserializer::from_str(r#"["x", "y\n", "x", "y\n"]"#);
+        let texts = json.deserialize_seq(InternedSeq(&mut interner)).unwrap();
+        assert_eq!(4, texts.len());
+        assert_eq!("y\n", texts[3].as_str());
+        assert_eq!(texts[0].as_str().as_ptr(), texts[2].as_str().as_ptr());
+        assert_eq!(texts[1].as_str().as_ptr(), texts[3].as_str().as_ptr());
+    }
+}
@@ -0,0 +1,321 @@
+use std::alloc::{self, Layout};
+use std::any::TypeId;
+use std::mem::ManuallyDrop;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{self, AtomicUsize, Ordering};
+
+/// The header of a shared buffer. It is directly followed by the bytes of the string,
+/// unless the buffer is owned (see [`IString::from_owner`]), in which case it is the start of an [`OwnerHeader`].
+/// Keeping it at two words makes inline buffers no larger than those of an `Arc<str>`.
+struct Header {
+    count: AtomicUsize,
+    /// The length of the string, with [`OWNED`] set for owned buffers.
+    len: usize,
+}
+
+const _: () = assert!(std::mem::size_of::<Header>() == 2 * std::mem::size_of::<usize>());
+
+/// Marks a [`Header`] as the start of an [`OwnerHeader`]. Allocations never exceed `isize::MAX` bytes,
+/// so the highest bit of the length is always free.
+const OWNED: usize = 1 << (usize::BITS - 1);
+
+/// The header of an owned buffer, followed by the owner holding the string elsewhere.
+#[repr(C)]
+struct OwnerHeader {
+    header: Header,
+    data: *const u8,
+    /// The type of the owner following the header.
+    owner: TypeId,
+    /// Drops the owner and frees the allocation.
+    release: unsafe fn(NonNull<Header>),
+}
+
+/// Copies a string into a buffer that is overwritten with zeros when dropped, see [`IString::new_sensitive`].
+#[cfg(feature = "zeroize")]
+struct SensitiveStr(Box<str>);
+
+#[cfg(feature = "zeroize")]
+impl AsRef<str> for SensitiveStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SensitiveStr {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+        self.0.zeroize()
+    }
+}
+
+/// An immutable, atomically reference-counted string, like `Arc<str>`.
+/// Unlike `Arc<str>`, the length is stored in the allocation, so this is a thin pointer.
+/// Buffers created by [`IString::new_bytes`] may hold arbitrary bytes and must only be accessed with [`IString::as_bytes`].
+pub(crate) struct IString(NonNull<Header>);
+
+// SAFETY: The string is immutable, owners are `Send + Sync` and the reference count is atomic.
+unsafe impl Send for IString {}
+unsafe impl Sync for IString {}
+
+/// Gets the layout of `head` followed by `tail`, and the offset of the tail.
+fn layout(head: Layout, tail: Layout) -> (Layout, usize) {
+    let (layout, offset) = head.extend(tail).expect("String too large to allocate");
+    (layout.pad_to_align(), offset)
+}
+
+fn bytes_layout(len: usize) -> (Layout, usize) {
+    let bytes = Layout::array::<u8>(len).expect("String too large to allocate");
+    layout(Layout::new::<Header>(), bytes)
+}
+
+fn owner_layout<O>() -> (Layout, usize) {
+    layout(Layout::new::<OwnerHeader>(), Layout::new::<O>())
+}
+
+/// Allocates a header followed by space for `tail`, returning the header and a pointer to the tail.
+/// The header still has to be initialized.
+fn allocate(layout: Layout, offset: usize) -> (NonNull<Header>, *mut u8) {
+    // SAFETY: The layout has a non-zero size because of the header.
+    let header = unsafe { alloc::alloc(layout) };
+    match NonNull::new(header.cast::<Header>()) {
+        Some(ptr) => (ptr, header.wrapping_add(offset)),
+        None => alloc::handle_alloc_error(layout),
+    }
+}
+
+unsafe fn release_owner<O>(header: NonNull<Header>) {
+    let (layout, offset) = owner_layout::<O>();
+    let base = header.as_ptr().cast::<u8>();
+    ptr::drop_in_place(base.add(offset).cast::<O>());
+    alloc::dealloc(base, layout)
+}
+
+impl IString {
+    /// Copies `s` into a new shared buffer.
+    pub(crate) fn new(s: &str) -> Self {
+        Self::new_bytes(s.as_bytes())
+    }
+
+    /// Copies `s` into a new shared buffer, which is overwritten with zeros when the last handle is dropped.
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn new_sensitive(s: &str) -> Self {
+        Self::from_owner(SensitiveStr(Box::from(s)))
+    }
+
+    /// Copies arbitrary `bytes` into a new shared buffer, which must not be accessed with [`IString::as_str`]
+    /// unless the bytes are known to be valid UTF-8.
+    pub(crate) fn new_bytes(bytes: &[u8]) -> Self {
+        let (layout, offset) = bytes_layout(bytes.len());
+        let (header, data) = allocate(layout, offset);
+        // SAFETY: The allocation is large enough for the header followed by `bytes.len()` bytes.
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), data, bytes.len());
+            header.as_ptr().write(Header {
+                count: AtomicUsize::new(1),
+                len: bytes.len(),
+            });
+        }
+        Self(header)
+    }
+
+    /// Shares the string held by `owner` without copying it. The owner is kept alive until the last handle is dropped.
+    pub(crate) fn from_owner<O: AsRef<str> + Send + Sync + 'static>(owner: O) -> Self {
+        let (layout, offset) = owner_layout::<O>();
+        let (header, tail) = allocate(layout, offset);
+        // SAFETY: The allocation is large enough for the owner header followed by an `O`.
+        // The owner is never moved or accessed mutably again, so the string it references stays valid.
+        unsafe {
+            let tail = tail.cast::<O>();
+            tail.write(owner);
+            let s = (*tail).as_ref();
+            header.as_ptr().cast::<OwnerHeader>().write(OwnerHeader {
+                header: Header {
+                    count: AtomicUsize::new(1),
+                    len: s.len() | OWNED,
+                },
+                data: s.as_ptr(),
+                owner: TypeId::of::<O>(),
+                release: release_owner::<O>,
+            });
+        }
+        Self(header)
+    }
+
+    /// Gets the owner header, if this is an owned buffer.
+    fn owner_header(&self) -> Option<&OwnerHeader> {
+        if self.header().len & OWNED == 0 {
+            return None;
+        }
+        // SAFETY: Owned buffers start with an `OwnerHeader`, whose first field is the `Header`.
+        Some(unsafe { self.0.cast::<OwnerHeader>().as_ref() })
+    }
+
+    fn len(&self) -> usize {
+        self.header().len & !OWNED
+    }
+
+    /// Is this the only handle to a buffer whose owner is of type `O` (see [`IString::from_owner`])?
+    pub(crate) fn is_unique_owner<O: 'static>(&self) -> bool {
+        self.owner_header()
+            .is_some_and(|owned| owned.owner == TypeId::of::<O>())
+            && self.header().count.load(Ordering::Acquire) == 1
+    }
+
+    /// Takes the owner out of the buffer if it is of type `O` and this is the only handle, see [`IString::is_unique_owner`].
+    /// Otherwise, the handle is returned unchanged.
+    pub(crate) fn try_unwrap_owner<O: 'static>(self) -> Result<O, Self> {
+        if !self.is_unique_owner::<O>() {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        let (layout, offset) = owner_layout::<O>();
+        // SAFETY: The owner following the header is an `O`, and as this is the last handle, nobody else can access it.
+        // The owner is moved out before the allocation is freed, so it is not dropped twice.
+        unsafe {
+            let base = this.0.as_ptr().cast::<u8>();
+            let owner = ptr::read(base.add(offset).cast::<O>());
+            alloc::dealloc(base, layout);
+            Ok(owner)
+        }
+    }
+
+    /// Overwrites the bytes with zeros if this is the only handle and they are stored inline or owned by a `String`
+    /// (or a sensitive buffer). Returns whether the bytes were wiped.
+    #[cfg(feature = "subtle")]
+    pub(crate) fn wipe_if_unique(&mut self) -> bool {
+        if self.header().count.load(Ordering::Acquire) != 1 {
+            return false;
+        }
+        let data = match self.owner_header() {
+            None => self.data() as *mut u8,
+            Some(owned) if owned.owner == TypeId::of::<String>() => owned.data as *mut u8,
+            #[cfg(feature = "zeroize")]
+            Some(owned) if owned.owner == TypeId::of::<SensitiveStr>() => owned.data as *mut u8,
+            Some(_) => return false,
+        };
+        for i in 0..self.len() {
+            // SAFETY: `data` points to `len` bytes that nobody else can access, as they are stored inline or owned
+            // by a uniquely held owner. Volatile writes are not optimized away. Zeros are valid UTF-8.
+            unsafe { ptr::write_volatile(data.add(i), 0) }
+        }
+        atomic::compiler_fence(Ordering::SeqCst);
+        true
+    }
+
+    fn header(&self) -> &Header {
+        // SAFETY: The header stays alive as long as any handle to it does.
+        unsafe { self.0.as_ref() }
+    }
+
+    fn data(&self) -> *const u8 {
+        match self.owner_header() {
+            Some(owned) => owned.data,
+            None => {
+                let (_, offset) = bytes_layout(0);
+                self.0.as_ptr().cast::<u8>().wrapping_add(offset)
+            }
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // SAFETY: Unless created by `new_bytes`, the bytes were copied from a `str` or are borrowed from an owner.
+        unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        // SAFETY: The bytes stay alive as long as any handle does, and are never mutated.
+        unsafe { std::slice::from_raw_parts(self.data(), self.len()) }
+    }
+
+    /// Do both handles refer to the same buffer? Buffers of different owners may still share their data.
+    pub(crate) fn ptr_eq(&self, other: &IString) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Clone for IString {
+    fn clone(&self) -> Self {
+        let old = self.header().count.fetch_add(1, Ordering::Relaxed);
+        // Like `Arc`, guard against the count overflowing through leaked handles.
+        if old > isize::MAX as usize {
+            std::process::abort();
+        }
+        Self(self.0)
+    }
+}
+
+impl Drop for IString {
+    fn drop(&mut self) {
+        if self.header().count.fetch_sub(1, Ordering::Release) != 1 {
+            return;
+        }
+        atomic::fence(Ordering::Acquire);
+        // SAFETY: This was the last handle, so nobody else can access the buffer anymore.
+        unsafe {
+            match self.owner_header() {
+                Some(owned) => (owned.release)(self.0),
+                None => {
+                    let (layout, _) = bytes_layout(self.len());
+                    alloc::dealloc(self.0.as_ptr().cast::<u8>(), layout)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::shared::IString;
+    use std::sync::atomic::Ordering;
+
+    #[test]
+    pub fn test_shared_string() {
+        let s = IString::new("añb");
+        let clones: Vec<IString> = (0..10).map(|_| s.clone()).collect();
+        drop(s);
+        assert!(clones.iter().all(|c| c.as_str() == "añb"));
+        assert_eq!(10, clones[3].header().count.load(Ordering::Relaxed));
+        assert_eq!("", IString::new("").as_str());
+        assert_eq!(&[0xff, 0][..], IString::new_bytes(&[0xff, 0]).as_bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    pub fn test_new_sensitive() {
+        let s = IString::new_sensitive("añb");
+        let clone = s.clone();
+        drop(s);
+        assert_eq!("añb", clone.as_str());
+        assert_eq!("", IString::new_sensitive("").as_str());
+    }
+
+    #[test]
+    pub fn test_try_unwrap_owner() {
+        let owned = IString::from_owner(String::from("añb"));
+        let shared = owned.clone();
+        let owned = owned.try_unwrap_owner::<String>().unwrap_err();
+        drop(shared);
+        let owned = owned.try_unwrap_owner::<Box<str>>().unwrap_err();
+        assert_eq!(
+            Some(String::from("añb")),
+            owned.try_unwrap_owner::<String>().ok()
+        );
+        assert!(IString::new("a").try_unwrap_owner::<String>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "subtle")]
+    pub fn test_wipe_if_unique() {
+        let mut inline = IString::new("añb");
+        let shared = inline.clone();
+        assert!(!inline.wipe_if_unique());
+        drop(shared);
+        assert!(inline.wipe_if_unique());
+        assert_eq!("\0\0\0\0", inline.as_str());
+        let mut owned = IString::from_owner(String::from("ab"));
+        assert!(owned.wipe_if_unique());
+        assert_eq!("\0\0", owned.as_str());
+        assert!(!IString::from_owner("static").wipe_if_unique());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::Text;
+
+/// Hashes `bytes` with 64-bit FNV-1a, which is stable across platforms and Rust versions.
+#[cfg(feature = "minhash")]
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Mixes `x` with the SplitMix64 finalizer, deriving independent hash functions from a single hash.
+#[cfg(feature = "minhash")]
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+impl Text {
+    /// Iterates over all overlapping windows of `k` consecutive `char`s (k-grams), as slices of `self`.
+    /// Yields nothing if the [`Text`] is shorter than `k` `char`s. Will panic if `k` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let shingles: Vec<Text> = Text::new("abcd").shingles(3).collect();
+    /// assert_eq!(2, shingles.len());
+    /// assert_eq!("bcd", shingles[1].as_str());
+    /// ```
+    pub fn shingles(&self, k: usize) -> impl Iterator<Item = Text> + '_ {
+        if k == 0 {
+            panic!("Shingle size must be at least 1")
+        }
+        let s = self.as_str();
+        let starts = s.char_indices().map(|(i, _)| i);
+        let ends = s
+            .char_indices()
+            .map(|(i, _)| i)
+            .skip(k)
+            .chain(std::iter::once(s.len()));
+        starts
+            .zip(ends)
+            .map(move |(start, end)| self.slice(start, end))
+            .take(s.chars().count().saturating_sub(k - 1))
+    }
+
+    /// Computes a MinHash signature of `n` values over the [`Text`]'s `k`-[`Text::shingles`].
+    /// The fraction of positions at which the signatures of two texts agree estimates the Jaccard similarity
+    /// of their shingle sets, see [`Text::minhash_similarity`]. Signatures are stable across platforms and versions.
+    /// Texts shorter than `k` `char`s have a signature of `u64::MAX` values. Will panic if `k` is 0.
+    /// Requires the `minhash` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let a = Text::new("the quick brown fox jumps over the lazy dog").minhash(4, 128);
+    /// let b = Text::new("the quick brown fox jumped over the lazy dog").minhash(4, 128);
+    /// let c = Text::new("lorem ipsum dolor sit amet").minhash(4, 128);
+    /// assert!(Text::minhash_similarity(&a, &b) > 0.5);
+    /// assert!(Text::minhash_similarity(&a, &c) < 0.2);
+    /// ```
+    #[cfg(feature = "minhash")]
+    pub fn minhash(&self, k: usize, n: usize) -> Vec<u64> {
+        let mut signature = vec![u64::MAX; n];
+        for shingle in self.shingles(k) {
+            let hash = fnv1a(shingle.as_str().as_bytes());
+            for (i, min) in signature.iter_mut().enumerate() {
+                *min = (*min).min(mix(hash ^ mix(i as u64)));
+            }
+        }
+        signature
+    }
+
+    /// Estimates the Jaccard similarity of two texts from their [`Text::minhash`] signatures,
+    /// which must have been computed with the same parameters. Returns 0 for empty signatures.
+    /// Requires the `minhash` feature.
+    #[cfg(feature = "minhash")]
+    pub fn minhash_similarity(a: &[u64], b: &[u64]) -> f64 {
+        if a.is_empty() {
+            return 0.0;
+        }
+        let equal = a.iter().zip(b).filter(|(x, y)| x == y).count();
+        equal as f64 / a.len().max(b.len()) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_shingles() {
+        let t = Text::new("xaäbx").slice(1, 5);
+        let shingles: Vec<String> = t.shingles(2).map(|s| s.to_string()).collect();
+        assert_eq!(vec!["aä", "äb"], shingles);
+        assert_eq!(3, t.shingles(1).count());
+        assert_eq!(1, t.shingles(3).count());
+        assert_eq!(0, t.shingles(4).count());
+    }
+
+    #[test]
+    #[cfg(feature = "minhash")]
+    pub fn test_minhash() {
+        let t = Text::new("abcdef");
+        let signature = t.minhash(2, 16);
+        assert_eq!(16, signature.len());
+        assert_eq!(signature, Text::new("xabcdefx").slice(1, 7).minhash(2, 16));
+        assert_eq!(1.0, Text::minhash_similarity(&signature, &signature));
+        assert_eq!(vec![u64::MAX; 4], Text::new("a").minhash(2, 4));
+        assert_eq!(0.0, Text::minhash_similarity(&[], &[]));
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{SliceError, Text};
+use std::ops::Range;
+
+impl Text {
+    /// Panics if the spans are not sorted and non-overlapping, or are not valid slices of `self`.
+    fn check_spans<'a, I: Iterator<Item = &'a Range<usize>>>(&self, spans: I) {
+        let mut previous = 0;
+        for span in spans {
+            if span.start < previous {
+                panic!(
+                    "Span {:?} overlaps or precedes the previous span ending at {}",
+                    span, previous
+                )
+            }
+            // Validates the span, panicking with a descriptive message
+            self.slice(span.start, span.end);
+            previous = span.end;
+        }
+    }
+
+    /// Finds all non-overlapping occurrences of `pattern`, returning their byte ranges in order.
+    /// An empty pattern matches nowhere.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("aaaa ba");
+    /// assert_eq!(vec![0..2, 2..4], text.find_all_spans("aa"));
+    /// ```
+    pub fn find_all_spans(&self, pattern: &str) -> Vec<Range<usize>> {
+        if pattern.is_empty() {
+            return Vec::new();
+        }
+        self.as_str()
+            .match_indices(pattern)
+            .map(|(i, m)| i..i + m.len())
+            .collect()
+    }
+
+    /// Splits the [`Text`] along the given byte ranges, yielding slices of `self` together with whether they lie within a span.
+    /// Matched and unmatched slices alternate; empty unmatched slices between adjacent spans are skipped.
+    /// Will panic if the spans are not sorted and non-overlapping, exceed the [`Text`]'s bounds
+    /// or do not start and end on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let text = Text::new("a needle in a haystack");
+    /// let spans = text.find_all_spans("needle");
+    /// let pieces: Vec<(Text, bool)> = text.annotate(&spans).collect();
+    /// assert_eq!(3, pieces.len());
+    /// assert_eq!(("needle", true), (pieces[1].0.as_str(), pieces[1].1));
+    /// assert_eq!((" in a haystack", false), (pieces[2].0.as_str(), pieces[2].1));
+    /// ```
+    pub fn annotate<'a>(
+        &'a self,
+        spans: &'a [Range<usize>],
+    ) -> impl Iterator<Item = (Text, bool)> + 'a {
+        self.check_spans(spans.iter());
+        let len = self.len();
+        let mut pos = 0;
+        let mut spans = spans.iter().peekable();
+        std::iter::from_fn(move || match spans.peek() {
+            Some(span) if pos < span.start => {
+                let piece = self.slice(pos, span.start);
+                pos = span.start;
+                Some((piece, false))
+            }
+            Some(span) => {
+                let piece = self.slice(span.start, span.end);
+                pos = span.end;
+                spans.next();
+                Some((piece, true))
+            }
+            None if pos < len => {
+                let piece = self.slice(pos, len);
+                pos = len;
+                Some((piece, false))
+            }
+            None => None,
+        })
+    }
+
+    /// Replaces each of the given byte ranges with its replacement string, building the result in a single allocation.
+    /// This is useful for applying several fix-its or filling in a template at once.
+    /// If there are no replacements, `self` is returned without copying.
+    /// Will panic if the ranges are not sorted and non-overlapping, exceed the [`Text`]'s bounds
+    /// or do not start and end on a `char` boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let source = Text::new("let x = foo(y);");
+    /// let fixed = source.splice(&[(4..5, "result"), (8..11, "bar")]);
+    /// assert_eq!("let result = bar(y);", fixed.as_str());
+    /// ```
+    pub fn splice(&self, replacements: &[(Range<usize>, &str)]) -> Text {
+        self.check_spans(replacements.iter().map(|(span, _)| span));
+        if replacements.is_empty() {
+            return self.clone();
+        }
+        let s = self.as_str();
+        let len = replacements
+            .iter()
+            .fold(s.len(), |len, (span, with)| len - span.len() + with.len());
+        let mut spliced = String::with_capacity(len);
+        let mut copied = 0;
+        for (span, with) in replacements {
+            spliced.push_str(&s[copied..span.start]);
+            spliced.push_str(with);
+            copied = span.end;
+        }
+        spliced.push_str(&s[copied..]);
+        Text::new(spliced.as_str())
+    }
+
+    /// Converts byte ranges produced elsewhere, e.g. by a machine learning tokenizer, into slices of `self` in bulk.
+    /// Ranges may overlap and be given in any order. All ranges are validated before any slice is created,
+    /// returning the error for the first invalid range.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{SliceError, Text};
+    ///
+    /// let text = Text::new("unbelievable");
+    /// let pieces = text.slices_from_offsets(&[(0, 2), (2, 6), (6, 12)]).unwrap();
+    /// assert_eq!("beli", pieces[1].as_str());
+    /// assert_eq!(Err(SliceError::InvertedRange { start: 6, end: 2 }), text.slices_from_offsets(&[(6, 2)]));
+    /// ```
+    pub fn slices_from_offsets(&self, offsets: &[(usize, usize)]) -> Result<Vec<Text>, SliceError> {
+        let s = self.as_str();
+        for &(start, end) in offsets {
+            if end < start {
+                return Err(SliceError::InvertedRange { start, end });
+            }
+            if end > s.len() {
+                return Err(SliceError::OutOfBounds {
+                    text_len: s.len(),
+                    start,
+                    len: end - start,
+                });
+            }
+            for index in [start, end] {
+                if !s.is_char_boundary(index) {
+                    return Err(SliceError::NotCharBoundary { index });
+                }
+            }
+        }
+        Ok(offsets
+            .iter()
+            .map(|&(start, end)| self.substring_raw(start, end - start))
+            .collect())
+    }
+
+    /// Cuts the [`Text`] at all the given byte positions, which must be sorted, returning the `positions.len() + 1` segments
+    /// between them as slices of `self`. All positions are validated in one sweep before any slice is created,
+    /// returning the error for the first invalid position. Repeated positions produce empty segments.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{SliceError, Text};
+    ///
+    /// let record = Text::new("2024-06-01");
+    /// let parts = record.split_at_byte_positions(&[4, 5, 7, 8]).unwrap();
+    /// let parts: Vec<&str> = parts.iter().map(Text::as_str).collect();
+    /// assert_eq!(vec!["2024", "-", "06", "-", "01"], parts);
+    /// assert_eq!(Err(SliceError::InvertedRange { start: 5, end: 4 }), record.split_at_byte_positions(&[5, 4]));
+    /// ```
+    pub fn split_at_byte_positions(&self, positions: &[usize]) -> Result<Vec<Text>, SliceError> {
+        let s = self.as_str();
+        let mut previous = 0;
+        for &position in positions {
+            if position < previous {
+                return Err(SliceError::InvertedRange {
+                    start: previous,
+                    end: position,
+                });
+            }
+            if position > s.len() {
+                return Err(SliceError::OutOfBounds {
+                    text_len: s.len(),
+                    start: previous,
+                    len: position - previous,
+                });
+            }
+            if !s.is_char_boundary(position) {
+                return Err(SliceError::NotCharBoundary { index: position });
+            }
+            previous = position;
+        }
+        Ok(std::iter::once(0)
+            .chain(positions.iter().copied())
+            .zip(positions.iter().copied().chain(std::iter::once(s.len())))
+            .map(|(start, end)| self.substring_raw(start, end - start))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SliceError, Text};
+
+    #[test]
+    pub fn test_split_at_byte_positions() {
+        let t = Text::new("xaäbx").slice(1, 5);
+        let as_strs =
+            |parts: Vec<Text>| parts.iter().map(|p| p.to_string()).collect::<Vec<String>>();
+        assert_eq!(
+            vec!["aäb"],
+            as_strs(t.split_at_byte_positions(&[]).unwrap())
+        );
+        assert_eq!(
+            vec!["", "a", "", "äb", ""],
+            as_strs(t.split_at_byte_positions(&[0, 1, 1, 4]).unwrap())
+        );
+        assert_eq!(
+            Err(SliceError::NotCharBoundary { index: 2 }),
+            t.split_at_byte_positions(&[1, 2])
+        );
+        assert_eq!(
+            Err(SliceError::OutOfBounds {
+                text_len: 4,
+                start: 1,
+                len: 4
+            }),
+            t.split_at_byte_positions(&[1, 5])
+        );
+    }
+
+    #[test]
+    pub fn test_annotate() {
+        let t = Text::new("xabñababx").slice(1, 9);
+        let spans = t.find_all_spans("ab");
+        assert_eq!(vec![0..2, 4..6, 6..8], spans);
+        let pieces: Vec<(Text, bool)> = t.annotate(&spans).collect();
+        let expected = vec![("ab", true), ("ñ", false), ("ab", true), ("ab", true)];
+        let expected: Vec<(Text, bool)> = expected
+            .into_iter()
+            .map(|(p, m)| (Text::new(p), m))
+            .collect();
+        assert_eq!(expected, pieces);
+        assert!(t.find_all_spans("").is_empty());
+        assert_eq!(1, t.annotate(&[]).count());
+        assert_eq!(0, Text::default().annotate(&[]).count());
+    }
+
+    #[test]
+    pub fn test_slices_from_offsets() {
+        let t = Text::new("xañbx").slice(1, 5);
+        let slices = t.slices_from_offsets(&[(1, 4), (0, 3), (4, 4)]).unwrap();
+        let slices: Vec<&str> = slices.iter().map(Text::as_str).collect();
+        assert_eq!(vec!["ñb", "añ", ""], slices);
+        assert_eq!(
+            Err(SliceError::NotCharBoundary { index: 2 }),
+            t.slices_from_offsets(&[(0, 1), (2, 4)])
+        );
+        assert_eq!(
+            Err(SliceError::OutOfBounds {
+                text_len: 4,
+                start: 3,
+                len: 2
+            }),
+            t.slices_from_offsets(&[(3, 5)])
+        );
+        assert!(t.slices_from_offsets(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    pub fn test_splice() {
+        let t = Text::new("(añb)").slice(1, 5);
+        assert_eq!("xañbz", t.splice(&[(0..0, "x"), (4..4, "z")]).as_str());
+        assert_eq!("a-b", t.splice(&[(1..3, "-")]).as_str());
+        assert_eq!("", t.splice(&[(0..1, ""), (1..4, "")]).as_str());
+        let unchanged = t.splice(&[]);
+        assert_eq!(t.as_str().as_ptr(), unchanged.as_str().as_ptr());
+    }
+
+    #[test]
+    #[should_panic(expected = "not on a char boundary")]
+    pub fn test_splice_invalid() {
+        Text::new("añb").splice(&[(0..2, "x")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "overlaps")]
+    pub fn test_annotate_overlapping() {
+        Text::new("abcd").annotate(&[1..3, 2..4]).count();
+    }
+}
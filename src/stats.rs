@@ -0,0 +1,226 @@
+use crate::Text;
+use std::collections::HashMap;
+
+/// The number of bytes at the start of a [`Text`] inspected by [`Text::is_probably_binary`].
+const BINARY_SAMPLE_LEN: usize = 8192;
+
+/// Basic statistics about a [`Text`], gathered in a single pass by [`Text::text_stats`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextStats {
+    chars: usize,
+    lines: usize,
+    words: usize,
+    frequencies: HashMap<char, usize>,
+}
+
+impl TextStats {
+    /// Gets the number of `char`s.
+    pub fn chars(&self) -> usize {
+        self.chars
+    }
+
+    /// Gets the number of lines, counted like `str::lines` does.
+    pub fn lines(&self) -> usize {
+        self.lines
+    }
+
+    /// Gets the number of words, i.e. maximal runs of non-whitespace `char`s.
+    pub fn words(&self) -> usize {
+        self.words
+    }
+
+    /// Gets the number of occurrences of every `char`.
+    pub fn frequencies(&self) -> &HashMap<char, usize> {
+        &self.frequencies
+    }
+
+    /// Gets the Shannon entropy of the `char` distribution in bits per `char`.
+    /// This is 0 for empty texts and texts repeating a single `char`; natural language text is typically around 4,
+    /// while compressed or random data approaches the logarithm of the number of distinct `char`s.
+    pub fn entropy(&self) -> f64 {
+        let total = self.chars as f64;
+        self.frequencies
+            .values()
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+impl Text {
+    /// Gathers [`TextStats`] about the [`Text`] in a single pass, e.g. to detect minified or binary-like content.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let stats = Text::new("to be or\nnot to be\n").text_stats();
+    /// assert_eq!(19, stats.chars());
+    /// assert_eq!(2, stats.lines());
+    /// assert_eq!(6, stats.words());
+    /// assert_eq!(Some(&4), stats.frequencies().get(&'o'));
+    /// ```
+    pub fn text_stats(&self) -> TextStats {
+        let mut stats = TextStats::default();
+        let mut in_word = false;
+        let mut last = None;
+        for c in self.as_str().chars() {
+            stats.chars += 1;
+            *stats.frequencies.entry(c).or_insert(0) += 1;
+            if c == '\n' {
+                stats.lines += 1;
+            }
+            let is_word = !c.is_whitespace();
+            if is_word && !in_word {
+                stats.words += 1;
+            }
+            in_word = is_word;
+            last = Some(c);
+        }
+        // A final line without terminator still counts
+        if last.is_some_and(|c| c != '\n') {
+            stats.lines += 1;
+        }
+        stats
+    }
+
+    /// Gets the Shannon entropy of the [`Text`]'s `char` distribution in bits per `char`, see [`TextStats::entropy`].
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!(0.0, Text::new("aaaa").entropy());
+    /// assert_eq!(2.0, Text::new("abcd").entropy());
+    /// ```
+    pub fn entropy(&self) -> f64 {
+        self.text_stats().entropy()
+    }
+
+    /// Counts the non-overlapping occurrences of `pattern`, like `str::matches(pattern).count()`,
+    /// but using a vectorized search instead of producing every match.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!(2, Text::new("aaaa").count_occurrences("aa"));
+    /// assert_eq!(4, Text::new("abc").count_occurrences(""));
+    /// ```
+    pub fn count_occurrences(&self, pattern: &str) -> usize {
+        let s = self.as_str();
+        match pattern.as_bytes() {
+            [] => self.char_len() + 1,
+            [byte] => memchr::memchr_iter(*byte, s.as_bytes()).count(),
+            needle => memchr::memmem::find_iter(s.as_bytes(), needle).count(),
+        }
+    }
+
+    /// Counts the lines like `str::lines().count()`, without splitting the [`Text`].
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!(2, Text::new("a\r\nb\n").count_lines());
+    /// ```
+    pub fn count_lines(&self) -> usize {
+        let s = self.as_str();
+        let unterminated = !s.is_empty() && !s.ends_with('\n');
+        memchr::memchr_iter(b'\n', s.as_bytes()).count() + usize::from(unterminated)
+    }
+
+    /// Counts the `char`s in one pass, like `str::chars().count()`. This is the same as [`Text::char_len`].
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!(4, Text::new("grüß").count_chars());
+    /// ```
+    pub fn count_chars(&self) -> usize {
+        self.char_len()
+    }
+
+    /// Guesses whether the [`Text`] holds binary data rather than text, e.g. a file decoded lossily, by inspecting its first 8 KiB.
+    /// Any NUL `char` counts as binary, as does more than 10% of control `char`s other than common whitespace and escape,
+    /// or more than 10% of replacement `char`s (`U+FFFD`) left by lossy decoding.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert!(!Text::new("fn main() {\n\tprintln!(\"hi\");\n}\n").is_probably_binary());
+    /// let bytes = [0x7f, b'E', b'L', b'F', 2, 1, 1, 0, 0];
+    /// assert!(Text::new(&*String::from_utf8_lossy(&bytes)).is_probably_binary());
+    /// ```
+    pub fn is_probably_binary(&self) -> bool {
+        let s = self.as_str();
+        let mut end = s.len().min(BINARY_SAMPLE_LEN);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        let sample = &s[..end];
+        if memchr::memchr(0, sample.as_bytes()).is_some() {
+            return true;
+        }
+        let mut chars = 0;
+        let mut suspicious = 0;
+        for c in sample.chars() {
+            chars += 1;
+            let control = c.is_control() && !matches!(c, '\t' | '\n' | '\r' | '\x0c' | '\x1b');
+            if control || c == char::REPLACEMENT_CHARACTER {
+                suspicious += 1;
+            }
+        }
+        suspicious * 10 > chars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_text_stats() {
+        let t = Text::new("x  grüße\r\n\n welt x").slice(1, 19);
+        let stats = t.text_stats();
+        assert_eq!(t.as_str().chars().count(), stats.chars());
+        assert_eq!(t.as_str().lines().count(), stats.lines());
+        assert_eq!(t.as_str().split_whitespace().count(), stats.words());
+        assert_eq!(Some(&4), stats.frequencies().get(&' '));
+        let empty = Text::default().text_stats();
+        assert_eq!((0, 0, 0), (empty.chars(), empty.lines(), empty.words()));
+        assert_eq!(0.0, empty.entropy());
+        assert_eq!(1.0, Text::new("abab").entropy());
+    }
+
+    #[test]
+    pub fn test_counts() {
+        let t = Text::new("xa\nbab\n\nab").slice(1, 10);
+        for pattern in ["", "a", "ab", "\n", "ba\n", "abab"] {
+            assert_eq!(
+                t.as_str().matches(pattern).count(),
+                t.count_occurrences(pattern)
+            );
+        }
+        for s in ["", "\n", "a", "a\n", "a\n\nb"] {
+            assert_eq!(s.lines().count(), Text::new(s).count_lines());
+        }
+        assert_eq!(t.as_str().lines().count(), t.count_lines());
+        assert_eq!(2, Text::new("äb").count_chars());
+    }
+
+    #[test]
+    pub fn test_is_probably_binary() {
+        assert!(!Text::default().is_probably_binary());
+        assert!(!Text::new("\x1b[1mbold\x1b[0m\r\n").is_probably_binary());
+        assert!(Text::new("text\0").is_probably_binary());
+        assert!(!Text::new("\x01 is fine in 10 chars").is_probably_binary());
+        assert!(Text::new("\u{fffd}\u{fffd}ab").is_probably_binary());
+        let late_nul = format!("{}\0", "ä".repeat(super::BINARY_SAMPLE_LEN));
+        assert!(!Text::new(late_nul.as_str()).is_probably_binary());
+    }
+}
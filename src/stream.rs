@@ -0,0 +1,108 @@
+use crate::Text;
+use memchr::memmem::Finder;
+use std::ops::Range;
+
+/// Searches for a pattern in a stream of [`Text`] chunks, e.g. the chunks of an [`AppendOnlyText`](crate::AppendOnlyText),
+/// including occurrences spanning chunk boundaries. Only the last few bytes of the stream are kept between chunks.
+///
+/// # Example
+/// ```
+/// use quetta::{StreamSearcher, Text};
+///
+/// let mut searcher = StreamSearcher::new("needle");
+/// assert!(searcher.push(&Text::new("hay nee")).is_empty());
+/// assert_eq!(vec![(0, 4..10)], searcher.push(&Text::new("dle hay")));
+/// assert_eq!(vec![(2, 1..7)], searcher.push(&Text::new(" needle")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamSearcher {
+    finder: Finder<'static>,
+    /// The last bytes of the stream, too few to contain the whole pattern.
+    carry: Vec<u8>,
+    /// The chunk index and offset within it of every byte in `carry`.
+    carry_positions: Vec<(usize, usize)>,
+    chunks: usize,
+}
+
+impl StreamSearcher {
+    /// Creates a [`StreamSearcher`] for `pattern`. An empty pattern never matches.
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            finder: Finder::new(pattern.as_bytes()).into_owned(),
+            carry: Vec::new(),
+            carry_positions: Vec::new(),
+            chunks: 0,
+        }
+    }
+
+    /// Gets the number of chunks pushed so far.
+    pub fn chunks_len(&self) -> usize {
+        self.chunks
+    }
+
+    /// Searches the next chunk of the stream, returning all occurrences ending in it, including overlapping ones.
+    /// Each occurrence is reported as the index of the chunk it starts in and its byte range relative to the start of
+    /// that chunk, so the range extends beyond that chunk if the occurrence spans a boundary.
+    pub fn push(&mut self, chunk: &Text) -> Vec<(usize, Range<usize>)> {
+        let pattern_len = self.finder.needle().len();
+        let index = self.chunks;
+        self.chunks += 1;
+        let mut matches = Vec::new();
+        if pattern_len == 0 {
+            return matches;
+        }
+        let bytes = chunk.as_str().as_bytes();
+        // Occurrences starting in the carried-over bytes
+        if !self.carry.is_empty() {
+            let mut window = self.carry.clone();
+            window.extend_from_slice(&bytes[..bytes.len().min(pattern_len - 1)]);
+            let mut pos = 0;
+            while let Some(i) = self.finder.find(&window[pos..]) {
+                let start = pos + i;
+                if start >= self.carry.len() {
+                    break;
+                }
+                let (chunk_index, offset) = self.carry_positions[start];
+                matches.push((chunk_index, offset..offset + pattern_len));
+                pos = start + 1;
+            }
+        }
+        let mut pos = 0;
+        while let Some(i) = self.finder.find(&bytes[pos..]) {
+            matches.push((index, pos + i..pos + i + pattern_len));
+            pos += i + 1;
+        }
+        // Keep the last `pattern_len - 1` bytes of the stream for the next chunk
+        let keep = pattern_len - 1;
+        let from_chunk = bytes.len().min(keep);
+        let from_carry = (keep - from_chunk).min(self.carry.len());
+        self.carry.drain(..self.carry.len() - from_carry);
+        self.carry_positions
+            .drain(..self.carry_positions.len() - from_carry);
+        let chunk_start = bytes.len() - from_chunk;
+        self.carry.extend_from_slice(&bytes[chunk_start..]);
+        self.carry_positions
+            .extend((chunk_start..bytes.len()).map(|offset| (index, offset)));
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{StreamSearcher, Text};
+
+    #[test]
+    pub fn test_stream_searcher() {
+        let mut searcher = StreamSearcher::new("aba");
+        let mut found = Vec::new();
+        for chunk in ["xa", "", "b", "ab", "a"] {
+            found.extend(searcher.push(&Text::new(chunk)));
+        }
+        assert_eq!(vec![(0, 1..4), (3, 0..3)], found);
+        assert_eq!(5, searcher.chunks_len());
+        let mut empty = StreamSearcher::new("");
+        assert!(empty.push(&Text::new("abc")).is_empty());
+        let mut single = StreamSearcher::new("ä");
+        assert_eq!(vec![(0, 0..2), (0, 2..4)], single.push(&Text::new("ää")));
+    }
+}
@@ -0,0 +1,490 @@
+use crate::Text;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::FromIterator;
+use std::ops::{Bound, Range, RangeBounds};
+
+/// A sorted, deduplicated set of [`Text`]s stored in a single backing buffer.
+/// Useful as a compact symbol table: lookups are binary searches and all entries share one allocation.
+///
+/// # Example
+/// ```
+/// use quetta::TextTable;
+///
+/// let table: TextTable = vec!["pear", "apple", "fig", "apple"].into_iter().collect();
+/// assert_eq!(3, table.len());
+/// assert_eq!(Ok(1), table.binary_search("fig"));
+/// assert_eq!("apple", table.get(0).unwrap().as_str());
+/// ```
+#[derive(Clone, Default)]
+pub struct TextTable {
+    buffer: Text,
+    ends: Vec<usize>,
+    /// The length of the longest entry, which bounds the prefixes [`TextTable::longest_prefix_of`] has to look up.
+    max_len: usize,
+}
+
+/// The error returned when decoding a [`TextTable`] from bytes fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableDecodeError {
+    /// The input ended in the middle of the table.
+    UnexpectedEnd,
+    /// A length or count does not fit into `usize`.
+    Overflow,
+    /// The string data is not valid UTF-8.
+    InvalidUtf8,
+    /// The entries are not sorted and deduplicated.
+    NotSorted,
+    /// There is data left after the end of the table.
+    TrailingBytes,
+    /// A compressed entry shares a longer prefix with its predecessor than the predecessor's length.
+    InvalidPrefix,
+}
+
+impl Display for TableDecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            TableDecodeError::UnexpectedEnd => "Unexpected end of table data",
+            TableDecodeError::Overflow => "Length in table data is too large",
+            TableDecodeError::InvalidUtf8 => "Table data is not valid UTF-8",
+            TableDecodeError::NotSorted => "Table entries are not sorted and deduplicated",
+            TableDecodeError::TrailingBytes => "Unexpected data after end of table",
+            TableDecodeError::InvalidPrefix => "Shared prefix in table data is too long",
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl std::error::Error for TableDecodeError {}
+
+impl TextTable {
+    /// Creates a table from the given strings, sorting and deduplicating them.
+    pub fn new<I: IntoIterator<Item = S>, S: AsRef<str>>(items: I) -> Self {
+        let mut items: Vec<S> = items.into_iter().collect();
+        items.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
+        items.dedup_by(|a, b| a.as_ref() == b.as_ref());
+        let mut buffer = String::with_capacity(items.iter().map(|s| s.as_ref().len()).sum());
+        let mut ends = Vec::with_capacity(items.len());
+        let mut max_len = 0;
+        for item in &items {
+            buffer.push_str(item.as_ref());
+            ends.push(buffer.len());
+            max_len = max_len.max(item.as_ref().len());
+        }
+        Self {
+            buffer: Text::new(buffer.as_str()),
+            ends,
+            max_len,
+        }
+    }
+
+    /// Gets the number of entries in the table.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Is the table empty?
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    fn bounds(&self, index: usize) -> Range<usize> {
+        let start = if index == 0 { 0 } else { self.ends[index - 1] };
+        start..self.ends[index]
+    }
+
+    fn entry_str(&self, index: usize) -> &str {
+        &self.buffer.as_str()[self.bounds(index)]
+    }
+
+    /// Gets the entry at `index` as a slice of the backing buffer.
+    pub fn get(&self, index: usize) -> Option<Text> {
+        if index < self.len() {
+            let bounds = self.bounds(index);
+            Some(self.buffer.slice(bounds.start, bounds.end))
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over all entries in sorted order.
+    pub fn iter(&self) -> impl Iterator<Item = Text> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+
+    /// Searches for `key`, like `slice::binary_search`.
+    /// Returns `Ok` with the index of the entry if found, or `Err` with the index where it would be inserted.
+    pub fn binary_search(&self, key: &str) -> Result<usize, usize> {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.entry_str(mid).cmp(key) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    /// Does the table contain `key`?
+    pub fn contains(&self, key: &str) -> bool {
+        self.binary_search(key).is_ok()
+    }
+
+    /// Looks up `key`, returning the stored entry.
+    pub fn lookup(&self, key: &str) -> Option<Text> {
+        self.binary_search(key).ok().and_then(|i| self.get(i))
+    }
+
+    /// Finds the longest entry that is a prefix of `text`, e.g. to dispatch a URL path or command line to a handler.
+    /// Returns the entry as a slice of the backing buffer together with the rest of `text` as a slice of `text`.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::{Text, TextTable};
+    ///
+    /// let routes = TextTable::new(vec!["/", "/api", "/api/users"]);
+    /// let path = Text::new("/api/users/42");
+    /// let (route, rest) = routes.longest_prefix_of(&path).unwrap();
+    /// assert_eq!("/api/users", route.as_str());
+    /// assert_eq!("/42", rest.as_str());
+    /// assert!(routes.longest_prefix_of(&Text::new("index.html")).is_none());
+    /// ```
+    pub fn longest_prefix_of(&self, text: &Text) -> Option<(Text, Text)> {
+        let s = text.as_str();
+        let mut longest = s.len().min(self.max_len);
+        while !s.is_char_boundary(longest) {
+            longest -= 1;
+        }
+        s[..longest]
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(longest))
+            .rev()
+            .find_map(|end| {
+                let entry = self.lookup(&s[..end])?;
+                Some((entry, text.slice(end, s.len())))
+            })
+    }
+
+    fn partition_point<P: Fn(&str) -> bool>(&self, pred: P) -> usize {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if pred(self.entry_str(mid)) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    /// Iterates over all entries within `range`, in sorted order.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::TextTable;
+    ///
+    /// let table = TextTable::new(vec!["a", "b", "c", "d"]);
+    /// let mid: Vec<String> = table.range("b".."d").map(|t| t.to_string()).collect();
+    /// assert_eq!(vec!["b", "c"], mid);
+    /// ```
+    pub fn range<'k, R: RangeBounds<&'k str>>(&self, range: R) -> impl Iterator<Item = Text> + '_ {
+        let start = match range.start_bound() {
+            Bound::Included(k) => self.partition_point(|e| e < *k),
+            Bound::Excluded(k) => self.partition_point(|e| e <= *k),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(k) => self.partition_point(|e| e <= *k),
+            Bound::Excluded(k) => self.partition_point(|e| e < *k),
+            Bound::Unbounded => self.len(),
+        };
+        (start..end.max(start)).filter_map(move |i| self.get(i))
+    }
+
+    /// Serializes the table into a compact byte format: the number of entries and each entry's length
+    /// as LEB128 varints, followed by the concatenated string data.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::TextTable;
+    ///
+    /// let table = TextTable::new(vec!["x", "yz"]);
+    /// let bytes = table.to_bytes();
+    /// assert_eq!(vec![2, 1, 2, b'x', b'y', b'z'], bytes);
+    /// let decoded = TextTable::from_bytes(&bytes).unwrap();
+    /// assert_eq!(table, decoded);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.buffer.len() + self.len() + 1);
+        write_varint(&mut bytes, self.len());
+        for i in 0..self.len() {
+            write_varint(&mut bytes, self.bounds(i).len());
+        }
+        bytes.extend_from_slice(self.buffer.as_str().as_bytes());
+        bytes
+    }
+
+    /// Deserializes a table written by [`TextTable::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TableDecodeError> {
+        let mut reader = bytes;
+        let count = read_varint(&mut reader)?;
+        let mut ends = Vec::with_capacity(count.min(reader.len()));
+        let mut total: usize = 0;
+        for _ in 0..count {
+            let len = read_varint(&mut reader)?;
+            total = total.checked_add(len).ok_or(TableDecodeError::Overflow)?;
+            ends.push(total);
+        }
+        if reader.len() < total {
+            return Err(TableDecodeError::UnexpectedEnd);
+        }
+        if reader.len() > total {
+            return Err(TableDecodeError::TrailingBytes);
+        }
+        let buffer = std::str::from_utf8(reader).map_err(|_| TableDecodeError::InvalidUtf8)?;
+        Self::from_parts(Text::new(buffer), ends)
+    }
+
+    /// Serializes the table using front coding: after the number of entries, each entry is stored as the length of the prefix
+    /// it shares with the previous entry and the length of the remaining suffix as LEB128 varints, followed by the suffix.
+    /// Since entries are sorted, this is much smaller than [`TextTable::to_bytes`] for tables of similar strings, like symbol tables.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::TextTable;
+    ///
+    /// let table = TextTable::new(vec!["quetta", "quenya"]);
+    /// let bytes = table.to_compressed_bytes();
+    /// assert_eq!(vec![2, 0, 6, b'q', b'u', b'e', b'n', b'y', b'a', 3, 3, b't', b't', b'a'], bytes);
+    /// assert_eq!(table, TextTable::from_compressed_bytes(&bytes).unwrap());
+    /// ```
+    pub fn to_compressed_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.buffer.len() / 2 + self.len() + 1);
+        write_varint(&mut bytes, self.len());
+        let mut previous: &[u8] = &[];
+        for i in 0..self.len() {
+            let entry = self.entry_str(i).as_bytes();
+            let shared = previous
+                .iter()
+                .zip(entry)
+                .take_while(|(a, b)| a == b)
+                .count();
+            write_varint(&mut bytes, shared);
+            write_varint(&mut bytes, entry.len() - shared);
+            bytes.extend_from_slice(&entry[shared..]);
+            previous = entry;
+        }
+        bytes
+    }
+
+    /// Deserializes a table written by [`TextTable::to_compressed_bytes`]. All entries are restored into a single backing buffer.
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self, TableDecodeError> {
+        let mut reader = bytes;
+        let count = read_varint(&mut reader)?;
+        let mut buffer: Vec<u8> = Vec::with_capacity(reader.len());
+        let mut ends = Vec::with_capacity(count.min(reader.len()));
+        let mut previous_start = 0;
+        for _ in 0..count {
+            let shared = read_varint(&mut reader)?;
+            let len = read_varint(&mut reader)?;
+            if shared > buffer.len() - previous_start {
+                return Err(TableDecodeError::InvalidPrefix);
+            }
+            if reader.len() < len {
+                return Err(TableDecodeError::UnexpectedEnd);
+            }
+            let start = buffer.len();
+            buffer.extend_from_within(previous_start..previous_start + shared);
+            buffer.extend_from_slice(&reader[..len]);
+            reader = &reader[len..];
+            ends.push(buffer.len());
+            previous_start = start;
+        }
+        if !reader.is_empty() {
+            return Err(TableDecodeError::TrailingBytes);
+        }
+        let buffer = String::from_utf8(buffer).map_err(|_| TableDecodeError::InvalidUtf8)?;
+        Self::from_parts(Text::new(buffer.as_str()), ends)
+    }
+
+    /// Creates a table from a buffer and entry end offsets, validating the invariants.
+    pub(crate) fn from_parts(buffer: Text, ends: Vec<usize>) -> Result<Self, TableDecodeError> {
+        let s = buffer.as_str();
+        let mut previous: Option<&str> = None;
+        let mut start = 0;
+        let mut max_len = 0;
+        for &end in &ends {
+            let entry = s.get(start..end).ok_or(TableDecodeError::InvalidUtf8)?;
+            if previous.is_some_and(|p| p >= entry) {
+                return Err(TableDecodeError::NotSorted);
+            }
+            previous = Some(entry);
+            max_len = max_len.max(entry.len());
+            start = end;
+        }
+        Ok(Self {
+            buffer,
+            ends,
+            max_len,
+        })
+    }
+}
+
+pub(crate) fn write_varint(bytes: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+pub(crate) fn read_varint(bytes: &mut &[u8]) -> Result<usize, TableDecodeError> {
+    let mut value: usize = 0;
+    let mut shift = 0;
+    loop {
+        let (&byte, rest) = bytes.split_first().ok_or(TableDecodeError::UnexpectedEnd)?;
+        *bytes = rest;
+        let part = (byte & 0x7f) as usize;
+        if shift >= usize::BITS || (part << shift) >> shift != part {
+            return Err(TableDecodeError::Overflow);
+        }
+        value |= part << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for TextTable {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl PartialEq for TextTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.ends == other.ends && self.buffer == other.buffer
+    }
+}
+
+impl Eq for TextTable {}
+
+impl Debug for TextTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TableDecodeError, Text, TextTable};
+
+    #[test]
+    pub fn test_table() {
+        let table = TextTable::new(vec!["delta", "alpha", "charlie", "bravo", "alpha", ""]);
+        let entries: Vec<String> = table.iter().map(|t| t.to_string()).collect();
+        assert_eq!(vec!["", "alpha", "bravo", "charlie", "delta"], entries);
+        assert_eq!(Ok(0), table.binary_search(""));
+        assert_eq!(Err(2), table.binary_search("b"));
+        assert_eq!(Err(5), table.binary_search("echo"));
+        assert!(table.contains("charlie"));
+        assert_eq!(None, table.get(5));
+        let range: Vec<String> = table
+            .range("b"..="charlie")
+            .map(|t| t.to_string())
+            .collect();
+        assert_eq!(vec!["bravo", "charlie"], range);
+        assert_eq!(2, table.range(.."bravo").count());
+        assert_eq!(0, table.range("z"..).count());
+    }
+
+    #[test]
+    pub fn test_longest_prefix_of() {
+        let table = TextTable::new(vec!["ä", "äb", "x"]);
+        let t = Text::new("-äbc").slice(1, 5);
+        let (entry, rest) = table.longest_prefix_of(&t).unwrap();
+        assert_eq!(("äb", "c"), (entry.as_str(), rest.as_str()));
+        let (entry, rest) = table.longest_prefix_of(&t.slice(0, 2)).unwrap();
+        assert_eq!(("ä", ""), (entry.as_str(), rest.as_str()));
+        assert!(table.longest_prefix_of(&Text::default()).is_none());
+        let with_empty = TextTable::new(vec![""]);
+        assert_eq!(
+            Some((Text::default(), t.clone())),
+            with_empty.longest_prefix_of(&t)
+        );
+        let long = Text::new("äb".repeat(1000).as_str());
+        let (entry, rest) = table.longest_prefix_of(&long).unwrap();
+        assert_eq!(("äb", 2997), (entry.as_str(), rest.len()));
+        let decoded = TextTable::from_bytes(&table.to_bytes()).unwrap();
+        assert_eq!(Some((entry, rest)), decoded.longest_prefix_of(&long));
+        let (entry, rest) = table.longest_prefix_of(&Text::new("ää")).unwrap();
+        assert_eq!(("ä", "ä"), (entry.as_str(), rest.as_str()));
+    }
+
+    #[test]
+    pub fn test_serialization() {
+        let long = "x".repeat(300);
+        let table = TextTable::new(vec![long.as_str(), "ä", "b"]);
+        let bytes = table.to_bytes();
+        assert_eq!(table, TextTable::from_bytes(&bytes).unwrap());
+        assert_eq!(
+            Err(TableDecodeError::UnexpectedEnd),
+            TextTable::from_bytes(&bytes[..bytes.len() - 1])
+        );
+        assert_eq!(
+            Err(TableDecodeError::NotSorted),
+            TextTable::from_bytes(&[2, 1, 1, b'b', b'a'])
+        );
+        assert_eq!(
+            Err(TableDecodeError::InvalidUtf8),
+            TextTable::from_bytes(&[2, 1, 1, 0xc3, 0xa4])
+        );
+        assert_eq!(
+            Err(TableDecodeError::TrailingBytes),
+            TextTable::from_bytes(&[1, 1, b'a', b'b'])
+        );
+        assert!(TextTable::from_bytes(&[0]).unwrap().is_empty());
+    }
+
+    #[test]
+    pub fn test_compressed_serialization() {
+        let long = "x".repeat(300);
+        let table = TextTable::new(vec!["", "äb", "äc", long.as_str(), "xy"]);
+        let bytes = table.to_compressed_bytes();
+        assert_eq!(table, TextTable::from_compressed_bytes(&bytes).unwrap());
+        let symbols = TextTable::new((0..100).map(|i| format!("std::collections::{}", i)));
+        let compressed = symbols.to_compressed_bytes();
+        assert!(compressed.len() * 4 < symbols.to_bytes().len());
+        assert_eq!(
+            symbols,
+            TextTable::from_compressed_bytes(&compressed).unwrap()
+        );
+        assert_eq!(
+            Err(TableDecodeError::UnexpectedEnd),
+            TextTable::from_compressed_bytes(&bytes[..bytes.len() - 1])
+        );
+        assert_eq!(
+            Err(TableDecodeError::InvalidPrefix),
+            TextTable::from_compressed_bytes(&[2, 0, 1, b'a', 2, 0])
+        );
+        assert_eq!(
+            Err(TableDecodeError::NotSorted),
+            TextTable::from_compressed_bytes(&[2, 0, 1, b'b', 0, 1, b'a'])
+        );
+        assert_eq!(
+            Err(TableDecodeError::InvalidUtf8),
+            TextTable::from_compressed_bytes(&[1, 0, 1, 0xc3])
+        );
+        assert_eq!(
+            Err(TableDecodeError::TrailingBytes),
+            TextTable::from_compressed_bytes(&[0, 0])
+        );
+        assert!(TextTable::from_compressed_bytes(&[0]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::Text;
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+impl Text {
+    /// Gets the last `n` `char`s as a slice of `self`, or the whole [`Text`] if it is shorter.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!("üße", Text::new("Grüße").last_chars(3).as_str());
+    /// ```
+    pub fn last_chars(&self, n: usize) -> Text {
+        let s = self.as_str();
+        let start = match n.checked_sub(1) {
+            Some(skip) => s.char_indices().rev().nth(skip).map_or(0, |(i, _)| i),
+            None => s.len(),
+        };
+        self.slice(start, s.len())
+    }
+
+    /// Gets the last `n` extended grapheme clusters as a slice of `self`, or the whole [`Text`] if it is shorter.
+    /// Requires the `unicode-segmentation` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!("e\u{301}!", Text::new("cafe\u{301}!").last_graphemes(2).as_str());
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn last_graphemes(&self, n: usize) -> Text {
+        let s = self.as_str();
+        let start = match n.checked_sub(1) {
+            Some(skip) => s
+                .grapheme_indices(true)
+                .rev()
+                .nth(skip)
+                .map_or(0, |(i, _)| i),
+            None => s.len(),
+        };
+        self.slice(start, s.len())
+    }
+
+    /// Gets the last `n` lines as a slice of `self`, or the whole [`Text`] if it has fewer lines.
+    /// Lines are counted like `str::lines` does, so a final line terminator does not start another line; it is kept in the result.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let output = Text::new("compiling\nlinking\ndone\n");
+    /// assert_eq!("linking\ndone\n", output.last_lines(2).as_str());
+    /// ```
+    pub fn last_lines(&self, n: usize) -> Text {
+        let bytes = self.as_str().as_bytes();
+        let content_end = match bytes.last() {
+            Some(b'\n') => bytes.len() - 1,
+            _ => bytes.len(),
+        };
+        let start = match n.checked_sub(1) {
+            Some(skip) => memchr::memrchr_iter(b'\n', &bytes[..content_end])
+                .nth(skip)
+                .map_or(0, |i| i + 1),
+            None => bytes.len(),
+        };
+        self.slice(start, bytes.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_last_chars() {
+        let t = Text::new("xañbx").slice(1, 5);
+        assert_eq!("", t.last_chars(0).as_str());
+        assert_eq!("ñb", t.last_chars(2).as_str());
+        assert_eq!("añb", t.last_chars(10).as_str());
+    }
+
+    #[test]
+    pub fn test_last_lines() {
+        let t = Text::new("a\r\nb\n\nc");
+        assert_eq!("", t.last_lines(0).as_str());
+        assert_eq!("c", t.last_lines(1).as_str());
+        assert_eq!("\nc", t.last_lines(2).as_str());
+        assert_eq!("b\n\nc", t.last_lines(3).as_str());
+        assert_eq!(t, t.last_lines(4));
+        assert_eq!(t, t.last_lines(5));
+        assert_eq!("\n", Text::new("a\n\n").last_lines(1).as_str());
+        assert_eq!("", Text::default().last_lines(1).as_str());
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn test_last_graphemes() {
+        let t = Text::new("a\r\n🇩🇪");
+        assert_eq!("🇩🇪", t.last_graphemes(1).as_str());
+        assert_eq!("\r\n🇩🇪", t.last_graphemes(2).as_str());
+        assert_eq!(t, t.last_graphemes(3));
+        assert_eq!("", t.last_graphemes(0).as_str());
+    }
+}
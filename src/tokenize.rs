@@ -0,0 +1,165 @@
+use crate::{CharClass, Text};
+
+#[derive(Debug, Clone)]
+enum Pattern {
+    Literal(String),
+    Class(CharClass),
+}
+
+/// A declarative tokenizer specification for [`Text::tokenize_with`], made of literals and character classes.
+/// At every position, the rule with the longest match wins; among equally long matches, the rule added first wins.
+/// Skip rules match like other rules, but their tokens are not yielded.
+///
+/// # Example
+/// ```
+/// use quetta::{CharClass, Text, TokenSpec};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Kind { Let, Ident, Number, Eq }
+///
+/// let mut spec = TokenSpec::new();
+/// spec.literal(Kind::Let, "let");
+/// spec.literal(Kind::Eq, "=");
+/// spec.class(Kind::Number, CharClass::ascii_digits());
+/// spec.class(Kind::Ident, CharClass::ascii_identifier());
+/// spec.skip(CharClass::ascii_whitespace());
+///
+/// let source = Text::new("let letter = 42");
+/// let tokens: Vec<(Kind, Text)> = source.tokenize_with(&spec).map(Result::unwrap).collect();
+/// let kinds: Vec<Kind> = tokens.iter().map(|(k, _)| *k).collect();
+/// assert_eq!(vec![Kind::Let, Kind::Ident, Kind::Eq, Kind::Number], kinds);
+/// assert_eq!("letter", tokens[1].1.as_str());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TokenSpec<K> {
+    /// Rules in the order they were added, with the token kind or `None` for skip rules.
+    rules: Vec<(Pattern, Option<K>)>,
+}
+
+impl<K> Default for TokenSpec<K> {
+    fn default() -> Self {
+        Self { rules: Vec::new() }
+    }
+}
+
+impl<K: Clone> TokenSpec<K> {
+    /// Creates an empty [`TokenSpec`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule matching exactly `literal` as a token of kind `kind`. Empty literals never match.
+    pub fn literal(&mut self, kind: K, literal: &str) {
+        self.rules
+            .push((Pattern::Literal(literal.to_string()), Some(kind)));
+    }
+
+    /// Adds a rule matching a non-empty run of characters in `class` as a token of kind `kind`.
+    pub fn class(&mut self, kind: K, class: CharClass) {
+        self.rules.push((Pattern::Class(class), Some(kind)));
+    }
+
+    /// Adds a rule skipping a non-empty run of characters in `class`, e.g. whitespace.
+    pub fn skip(&mut self, class: CharClass) {
+        self.rules.push((Pattern::Class(class), None));
+    }
+
+    /// Adds a rule skipping `literal`.
+    pub fn skip_literal(&mut self, literal: &str) {
+        self.rules
+            .push((Pattern::Literal(literal.to_string()), None));
+    }
+
+    /// Gets the number of rules.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Are there no rules?
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Finds the longest match at byte offset `pos`, returning its length and the index of the rule.
+    fn longest_match(&self, text: &Text, pos: usize) -> Option<(usize, usize)> {
+        let rest = &text.as_str()[pos..];
+        let mut best: Option<(usize, usize)> = None;
+        for (index, (pattern, _)) in self.rules.iter().enumerate() {
+            let len = match pattern {
+                Pattern::Literal(literal) if rest.starts_with(literal.as_str()) => literal.len(),
+                Pattern::Literal(_) => 0,
+                Pattern::Class(class) => text.span_of_class(class, pos).len(),
+            };
+            if len > best.map_or(0, |(l, _)| l) {
+                best = Some((len, index));
+            }
+        }
+        best
+    }
+}
+
+impl Text {
+    /// Splits the [`Text`] into tokens according to `spec`, yielding their kinds and the tokens as slices of `self`.
+    /// If no rule matches at some position, the unmatched `char` is yielded as an error and tokenizing continues after it.
+    pub fn tokenize_with<'a, K: Clone>(
+        &'a self,
+        spec: &'a TokenSpec<K>,
+    ) -> impl Iterator<Item = Result<(K, Text), Text>> + 'a {
+        let mut pos = 0;
+        std::iter::from_fn(move || {
+            while pos < self.len() {
+                let start = pos;
+                match spec.longest_match(self, start) {
+                    Some((len, rule)) => {
+                        pos += len;
+                        if let Some(kind) = &spec.rules[rule].1 {
+                            return Some(Ok((kind.clone(), self.substring(start, len))));
+                        }
+                    }
+                    None => {
+                        let c = self.as_str()[start..].chars().next()?;
+                        pos += c.len_utf8();
+                        return Some(Err(self.substring(start, c.len_utf8())));
+                    }
+                }
+            }
+            None
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CharClass, Text, TokenSpec};
+
+    #[test]
+    pub fn test_tokenize_with() {
+        let mut spec = TokenSpec::new();
+        spec.literal("op", "=");
+        spec.literal("op", "==");
+        spec.literal("", "");
+        spec.class("num", CharClass::ascii_digits());
+        spec.skip(CharClass::ascii_whitespace());
+        spec.skip_literal("#");
+        assert_eq!(6, spec.len());
+        let t = Text::new("1 == 2 =#3ä4");
+        let tokens: Vec<Result<(&str, String), String>> = t
+            .tokenize_with(&spec)
+            .map(|r| {
+                r.map(|(k, t)| (k, t.to_string()))
+                    .map_err(|t| t.to_string())
+            })
+            .collect();
+        let expected: Vec<Result<(&str, String), String>> = vec![
+            Ok(("num", String::from("1"))),
+            Ok(("op", String::from("=="))),
+            Ok(("num", String::from("2"))),
+            Ok(("op", String::from("="))),
+            Ok(("num", String::from("3"))),
+            Err(String::from("ä")),
+            Ok(("num", String::from("4"))),
+        ];
+        assert_eq!(expected, tokens);
+        assert_eq!(0, Text::new("   ").tokenize_with(&spec).count());
+    }
+}
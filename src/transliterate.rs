@@ -0,0 +1,38 @@
+use crate::Text;
+
+impl Text {
+    /// Transliterates the [`Text`] to ASCII, e.g. for generating slugs from titles.
+    /// Returns `self` without copying if it is already ASCII. `char`s without a known transliteration become `[?]`.
+    /// Requires the `deunicode` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// assert_eq!("Zolc", Text::new("Żółć").to_ascii_transliterated().as_str());
+    /// assert_eq!("AEneid", Text::new("Æneid").to_ascii_transliterated().as_str());
+    /// ```
+    pub fn to_ascii_transliterated(&self) -> Text {
+        if self.as_str().is_ascii() {
+            return self.clone();
+        }
+        Text::new(&*deunicode::deunicode(self.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_to_ascii_transliterated() {
+        let t = Text::new("äbc").slice(2, 4);
+        let ascii = t.to_ascii_transliterated();
+        assert_eq!(t.as_str().as_ptr(), ascii.as_str().as_ptr());
+        assert_eq!(
+            "Grusse",
+            Text::new("Grüße").to_ascii_transliterated().as_str()
+        );
+        assert_eq!("", Text::default().to_ascii_transliterated().as_str());
+    }
+}
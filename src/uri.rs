@@ -0,0 +1,129 @@
+use crate::Text;
+
+/// The components of a URI, as split by [`Text::split_uri`] following the generic syntax of RFC 3986.
+/// Delimiters (`:`, `//`, `?` and `#`) are not part of the components.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct UriParts<T> {
+    pub scheme: Option<T>,
+    pub authority: Option<T>,
+    /// The path, which is always present, but may be empty.
+    pub path: T,
+    pub query: Option<T>,
+    pub fragment: Option<T>,
+}
+
+impl Text {
+    /// Splits an email address at its last `@` into the local part and the domain, as slices of `self`.
+    /// The address is not validated beyond both parts being non-empty.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let address = Text::new("\"a@b\"@example.org");
+    /// let (local, domain) = address.split_email().unwrap();
+    /// assert_eq!("\"a@b\"", local.as_str());
+    /// assert_eq!("example.org", domain.as_str());
+    /// assert_eq!(None, Text::new("nobody@").split_email());
+    /// ```
+    pub fn split_email(&self) -> Option<(Text, Text)> {
+        let at = memchr::memrchr(b'@', self.as_str().as_bytes())?;
+        if at == 0 || at + 1 == self.len() {
+            return None;
+        }
+        Some((self.slice(0, at), self.slice(at + 1, self.len())))
+    }
+
+    /// Splits a URI into its scheme, authority, path, query and fragment, as slices of `self`.
+    /// This never fails: like the regular expression in RFC 3986, it accepts any input without validating it.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let uri = Text::new("https://user@example.org:8080/a/b?x=1#top");
+    /// let parts = uri.split_uri();
+    /// assert_eq!(Some("https"), parts.scheme.as_ref().map(Text::as_str));
+    /// assert_eq!(Some("user@example.org:8080"), parts.authority.as_ref().map(Text::as_str));
+    /// assert_eq!("/a/b", parts.path.as_str());
+    /// assert_eq!(Some("x=1"), parts.query.as_ref().map(Text::as_str));
+    /// assert_eq!(Some("top"), parts.fragment.as_ref().map(Text::as_str));
+    /// ```
+    pub fn split_uri(&self) -> UriParts<Text> {
+        let s = self.as_str();
+        let mut pos = 0;
+        let scheme = match s.find([':', '/', '?', '#']) {
+            Some(colon) if colon > 0 && s.as_bytes()[colon] == b':' => {
+                pos = colon + 1;
+                Some(self.slice(0, colon))
+            }
+            _ => None,
+        };
+        let authority = if s[pos..].starts_with("//") {
+            let start = pos + 2;
+            let end = s[start..]
+                .find(['/', '?', '#'])
+                .map_or(s.len(), |i| start + i);
+            pos = end;
+            Some(self.slice(start, end))
+        } else {
+            None
+        };
+        let fragment_start = s[pos..].find('#').map(|i| pos + i);
+        let end = fragment_start.unwrap_or(s.len());
+        let query_start = s[pos..end].find('?').map(|i| pos + i);
+        let path = self.slice(pos, query_start.unwrap_or(end));
+        UriParts {
+            scheme,
+            authority,
+            path,
+            query: query_start.map(|q| self.slice(q + 1, end)),
+            fragment: fragment_start.map(|f| self.slice(f + 1, s.len())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Text, UriParts};
+
+    fn split(uri: &str) -> UriParts<String> {
+        let parts = Text::new(uri).split_uri();
+        let string = |t: Text| t.to_string();
+        UriParts {
+            scheme: parts.scheme.map(string),
+            authority: parts.authority.map(string),
+            path: parts.path.to_string(),
+            query: parts.query.map(string),
+            fragment: parts.fragment.map(string),
+        }
+    }
+
+    #[test]
+    pub fn test_split_uri() {
+        let mailto = split("mailto:someone@example.org");
+        assert_eq!(Some(String::from("mailto")), mailto.scheme);
+        assert_eq!(None, mailto.authority);
+        assert_eq!("someone@example.org", mailto.path);
+        let relative = split("../a:b?q#f?g");
+        assert_eq!(None, relative.scheme);
+        assert_eq!("../a:b", relative.path);
+        assert_eq!(Some(String::from("q")), relative.query);
+        assert_eq!(Some(String::from("f?g")), relative.fragment);
+        let empty = split("file://");
+        assert_eq!(Some(String::new()), empty.authority);
+        assert_eq!("", empty.path);
+        assert_eq!(UriParts::default(), split(""));
+        assert_eq!(Some(String::new()), split("?").query);
+    }
+
+    #[test]
+    pub fn test_split_email() {
+        let t = Text::new("<a@b@c>").slice(1, 6);
+        let (local, domain) = t.split_email().unwrap();
+        assert_eq!("a@b", local.as_str());
+        assert_eq!("c", domain.as_str());
+        assert_eq!(None, Text::new("@c").split_email());
+        assert_eq!(None, Text::new("abc").split_email());
+    }
+}
@@ -0,0 +1,160 @@
+use crate::Text;
+use std::cmp::Ordering;
+
+fn is_number(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Compares two strings of ASCII digits numerically, without overflow.
+fn cmp_numeric(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+fn cmp_pre_release(a: Option<&Text>, b: Option<&Text>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            let mut a = a.as_str().split('.');
+            let mut b = b.as_str().split('.');
+            loop {
+                let ord = match (a.next(), b.next()) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => return Ordering::Less,
+                    (Some(_), None) => return Ordering::Greater,
+                    (Some(x), Some(y)) => match (is_number(x), is_number(y)) {
+                        (true, true) => cmp_numeric(x, y),
+                        (true, false) => Ordering::Less,
+                        (false, true) => Ordering::Greater,
+                        (false, false) => x.cmp(y),
+                    },
+                };
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+        }
+    }
+}
+
+impl Text {
+    /// Splits a semantic version (`MAJOR.MINOR.PATCH[-PRE][+BUILD]`, optionally prefixed with `v`)
+    /// into its major, minor and patch numbers and the optional pre-release part, as slices of `self`.
+    /// Build metadata is dropped. Returns `None` if the [`Text`] is not a version.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    ///
+    /// let (major, minor, patch, pre) = Text::new("v1.12.0-rc.1+build5").parse_version().unwrap();
+    /// assert_eq!("1", major.as_str());
+    /// assert_eq!("12", minor.as_str());
+    /// assert_eq!("0", patch.as_str());
+    /// assert_eq!("rc.1", pre.unwrap().as_str());
+    /// ```
+    pub fn parse_version(&self) -> Option<(Text, Text, Text, Option<Text>)> {
+        let s = self.as_str();
+        let start = if s.starts_with('v') { 1 } else { 0 };
+        let end = s.find('+').unwrap_or(s.len());
+        let (core_end, pre) = match s[..end].find('-') {
+            Some(dash) if dash + 1 < end => (dash, Some(self.slice(dash + 1, end))),
+            Some(_) => return None,
+            None => (end, None),
+        };
+        let mut parts = Vec::with_capacity(3);
+        let mut part_start = start;
+        for (i, _) in s[start..core_end].match_indices('.') {
+            parts.push((part_start, start + i));
+            part_start = start + i + 1;
+        }
+        parts.push((part_start, core_end));
+        if parts.len() != 3 || !parts.iter().all(|(a, b)| is_number(&s[*a..*b])) {
+            return None;
+        }
+        let slice = |(a, b): (usize, usize)| self.slice(a, b);
+        Some((slice(parts[0]), slice(parts[1]), slice(parts[2]), pre))
+    }
+
+    /// Compares two [`Text`]s as semantic versions, following the precedence rules of semver 2.0:
+    /// numbers are compared numerically, a pre-release sorts before the release, and build metadata is ignored.
+    /// Valid versions sort before invalid ones; invalid ones are compared as plain strings.
+    ///
+    /// # Example
+    /// ```
+    /// use quetta::Text;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = Text::new("1.10.0");
+    /// let b = Text::new("1.9.3");
+    /// assert_eq!(Ordering::Greater, a.cmp_semver(&b));
+    /// assert_eq!(Ordering::Less, Text::new("1.0.0-alpha").cmp_semver(&Text::new("1.0.0")));
+    /// ```
+    pub fn cmp_semver(&self, other: &Text) -> Ordering {
+        match (self.parse_version(), other.parse_version()) {
+            (Some(a), Some(b)) => cmp_numeric(a.0.as_str(), b.0.as_str())
+                .then_with(|| cmp_numeric(a.1.as_str(), b.1.as_str()))
+                .then_with(|| cmp_numeric(a.2.as_str(), b.2.as_str()))
+                .then_with(|| cmp_pre_release(a.3.as_ref(), b.3.as_ref())),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => self.cmp(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Text;
+
+    #[test]
+    pub fn test_parse_version() {
+        assert!(Text::new("1.2").parse_version().is_none());
+        assert!(Text::new("1.2.3.4").parse_version().is_none());
+        assert!(Text::new("1.2.x").parse_version().is_none());
+        assert!(Text::new("1.2.3-").parse_version().is_none());
+        let (_, _, patch, pre) = Text::new("0.0.7+meta").parse_version().unwrap();
+        assert_eq!("7", patch.as_str());
+        assert!(pre.is_none());
+    }
+
+    #[test]
+    pub fn test_semver_ordering() {
+        let mut versions: Vec<Text> = vec![
+            "1.0.0",
+            "not-a-version",
+            "1.0.0-rc.1",
+            "1.0.0-beta.11",
+            "1.0.0-alpha",
+            "1.0.0-beta.2",
+            "1.0.0-alpha.1",
+            "18446744073709551616.0.0",
+            "1.0.0-beta",
+            "1.0.0-alpha.beta",
+            "v2.0.0",
+        ]
+        .into_iter()
+        .map(Text::new)
+        .collect();
+        versions.sort_by(|a, b| a.cmp_semver(b));
+        let sorted: Vec<&str> = versions.iter().map(Text::as_str).collect();
+        assert_eq!(
+            vec![
+                "1.0.0-alpha",
+                "1.0.0-alpha.1",
+                "1.0.0-alpha.beta",
+                "1.0.0-beta",
+                "1.0.0-beta.2",
+                "1.0.0-beta.11",
+                "1.0.0-rc.1",
+                "1.0.0",
+                "v2.0.0",
+                "18446744073709551616.0.0",
+                "not-a-version",
+            ],
+            sorted
+        );
+    }
+}